@@ -11,16 +11,203 @@
 //! [corresponding]: https://docs.rs/corresponding/
 
 use proc_macro::TokenStream;
-use quote::{ToTokens, __private::TokenTree};
+use quote::{__private::TokenTree, format_ident, ToTokens};
 use syn::{
-    parse_macro_input, parse_quote, AngleBracketedGenericArguments, GenericArgument, Ident, Item,
-    ItemMod, Path, PathArguments, PathSegment, Stmt, Type, TypePath,
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    token, AngleBracketedGenericArguments, Attribute, Expr, ExprLit, Field, GenericArgument, Ident,
+    Item, ItemMod, Lit, Pat, Path, PathArguments, PathSegment, Stmt, Token, Type, TypeParamBound,
+    TypePath, Visibility, WhereClause, WherePredicate,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct OptionType {
     pub ident: Ident,
     pub option: bool,
+    pub ty: Type,
+}
+
+/// How a source field of type `Option<T>` is moved to the target field
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum OptionPolicy {
+    /// Only set the target field when the source field is `Some(value)`
+    #[default]
+    Some,
+    /// Also set an `Option<T>` target field to `None` when the source field is `None`
+    Overwrite,
+    /// Set the target field to its default value when the source field is `None`
+    Default,
+}
+
+/// How the value of a source field is combined with the current value of the target field
+#[derive(Debug, Clone)]
+enum MergeStrategy {
+    /// Move the elements of the value to the target field with `append`
+    Append,
+    /// Add the elements of the value to the target field with `extend`
+    Extend,
+    /// Add the value to the target field with `+=`
+    Sum,
+    /// Keep the largest of both values
+    Max,
+    /// Call a function with a mutable reference to the target field and the value
+    Custom(Box<Expr>),
+}
+
+/// The options given to a field with the `#[corresponding(...)]` attribute
+#[derive(Debug, Default)]
+struct FieldOptions {
+    pub option: Option<OptionPolicy>,
+    pub audit: Option<Expr>,
+    pub skip: bool,
+    pub default: Option<Expr>,
+    pub compute: Vec<ComputeFunction>,
+    pub flatten: bool,
+    pub from: Option<SourcePath>,
+    pub merge: Option<MergeStrategy>,
+    pub skip_if: Option<Expr>,
+}
+
+/// The path given with the `from` option, like `address.city` or `profile?.avatar`.
+/// Each segment is the name of a field, and whether it is followed by `?`.
+#[derive(Debug, Clone)]
+struct SourcePath {
+    pub value: Expr,
+    pub name: String,
+    pub segments: Vec<(Ident, bool)>,
+}
+
+/// A function given with the `compute` option, with the struct it computes the value from.
+/// Without a struct, the value is computed from every other struct of the module.
+#[derive(Debug, Clone)]
+struct ComputeFunction {
+    pub source: Option<Ident>,
+    pub function: Expr,
+}
+
+/// The options given to a struct with the `#[corresponding(...)]` attribute
+#[derive(Debug, Default)]
+struct StructOptions {
+    pub exhaustive: bool,
+    pub roundtrip: Vec<Ident>,
+    pub patch: bool,
+    /// The tuples of structs to split the struct into
+    pub split: Vec<Vec<Ident>>,
+    /// The traits to implement for the struct besides moving and cloning, for all other structs
+    pub traits: TraitOptions,
+}
+
+/// The optional traits implemented for a struct, each enabled by the option with its name
+#[derive(Debug, Default)]
+struct TraitOptions {
+    pub take: bool,
+    pub swap: bool,
+    pub eq: bool,
+    pub tracked: bool,
+    pub changed: bool,
+    pub audited: bool,
+    pub undo: bool,
+    pub fields: bool,
+    pub checked: bool,
+    pub fill: bool,
+    pub replace: bool,
+    pub try_move: bool,
+}
+
+/// A single `name`, `name = value` or `name(arguments)` argument of the `#[corresponding(...)]` attribute
+struct Argument {
+    pub name: Ident,
+    pub value: Option<Expr>,
+    pub arguments: Option<Punctuated<Argument, Token![,]>>,
+}
+
+impl Parse for Argument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let mut value = None;
+        let mut arguments = None;
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            value = Some(input.parse()?);
+        } else if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            arguments = Some(content.parse_terminated(Argument::parse)?);
+        }
+        Ok(Argument {
+            name,
+            value,
+            arguments,
+        })
+    }
+}
+
+/// A field of a struct, or a field of a flattened struct inside it, with the path to reach it
+#[derive(Clone)]
+struct FlatField<'a> {
+    pub field: &'a Field,
+    pub path: Punctuated<Ident, Token![.]>,
+}
+
+/// A field of a struct, reached through one or more fields of type `Option<T>` or `Result<T, E>`
+#[derive(Clone)]
+struct NestedField<'a> {
+    pub field: &'a Field,
+    /// Whether the field itself is of type `Option<T>`
+    pub option: bool,
+    /// The path to the first `Option` field
+    pub prefix: Punctuated<Ident, Token![.]>,
+    /// The paths after each `Option` field. The last path leads to the field itself.
+    pub steps: Vec<Punctuated<Ident, Token![.]>>,
+    /// The path as given with the `from` option
+    pub name: String,
+    /// The error type when the first field is of type `Result<T, E>` instead of `Option<T>`
+    pub result: Option<Box<Type>>,
+    /// Whether the field is taken out of the first `Option` field when moving,
+    /// because a later field is read through the same `Option`
+    pub take: bool,
+}
+
+/// Where the value of a field of the left struct comes from
+enum Source<'a> {
+    /// The corresponding field of the right struct
+    Field(FlatField<'a>),
+    /// The field at the path given with the `from` option, when the path contains `?`,
+    /// or the corresponding field of type `Result<T, E>`
+    Nested(NestedField<'a>),
+    /// The function given with the `compute` option, called with a reference to the right struct
+    Compute(Box<Expr>),
+}
+
+/// A field of the left struct and the source of its value in the right struct
+struct FieldPair<'a> {
+    pub l_field: FlatField<'a>,
+    pub source: Source<'a>,
+    pub l_type: OptionType,
+    pub r_type: OptionType,
+    pub l_options: FieldOptions,
+    pub policy: OptionPolicy,
+    pub skip_if: Option<Expr>,
+}
+
+/// A new left struct, constructed before the corresponding fields are moved to it
+struct NewLhs {
+    pub value: Expr,
+    pub where_clause: Option<WhereClause>,
+}
+
+/// How the fields of the right struct are accessed
+#[derive(Debug, Clone, Copy)]
+enum Access {
+    /// `rhs` is taken by value
+    Move,
+    /// `rhs` is taken by reference and the fields are cloned
+    Clone,
+    /// `rhs` is taken by mutable reference and the fields are taken with [std::mem::take]
+    Take,
 }
 
 /// Use this macro on a module to generate [MoveCorresponding] implementations for all
@@ -127,7 +314,12 @@ struct OptionType {
 /// }
 /// ```
 ///
+/// Fields can be configured with the `#[corresponding(...)]` attribute, like
+/// `#[corresponding(option = "overwrite")]`. See the [corresponding] crate documentation
+/// for all options.
+///
 /// [MoveCorresponding]: https://docs.rs/corresponding/trait.MoveCorresponding.html
+/// [corresponding]: https://docs.rs/corresponding/
 ///
 #[proc_macro_attribute]
 pub fn derive_corresponding(_metadata: TokenStream, input: TokenStream) -> TokenStream {
@@ -135,26 +327,165 @@ pub fn derive_corresponding(_metadata: TokenStream, input: TokenStream) -> Token
 
     if let Some((_, ref mut items)) = input.content {
         let structs = get_structs(items);
+        remove_corresponding_attributes(items);
 
-        for l in &structs {
-            for r in &structs {
-                if l != r {
-                    items.push(generate_move_corresponding_impl(l, r));
-                    if has_derive(l, "Default") {
-                        items.push(generate_from_impl(l, r));
-                        if has_derive(r, "Clone") {
-                            items.push(generate_from_cloned_impl(l, r));
-                        }
-                    }
-                    if has_derive(r, "Clone") {
-                        items.push(generate_clone_corresponding_impl(l, r));
+        if let Err(error) = generate_impls(&structs, items) {
+            let mut output = input.into_token_stream();
+            output.extend(error.to_compile_error());
+            return TokenStream::from(output);
+        }
+    }
+
+    TokenStream::from(input.into_token_stream())
+}
+
+/// Generate the implementations for all combinations of structs and add them to the items
+fn generate_impls(structs: &[syn::ItemStruct], items: &mut Vec<Item>) -> syn::Result<()> {
+    // Check the options of all structs and fields, also of the fields without a corresponding field
+    let mut options = vec![];
+    for item_struct in structs {
+        options.push(get_struct_options(item_struct)?);
+        for field in &item_struct.fields {
+            for compute in get_field_options(field)?.compute {
+                if let Some(source) = compute.source {
+                    if !structs.iter().any(|r| r.ident == source) {
+                        return Err(syn::Error::new_spanned(
+                            &source,
+                            format!("struct `{source}` not found in this module"),
+                        ));
                     }
                 }
             }
         }
     }
 
-    TokenStream::from(input.into_token_stream())
+    // The structs that are parts of other structs are not checked for exhaustive structs
+    let nested_structs = get_nested_structs(structs)?;
+
+    // Patches are the structs with the `patch` option
+    let patches: Vec<&syn::ItemStruct> = structs
+        .iter()
+        .zip(&options)
+        .filter(|(_, options)| options.patch)
+        .map(|(p, _)| p)
+        .collect();
+
+    for (l, options) in structs.iter().zip(options) {
+        let traits = &options.traits;
+        for ident in &options.roundtrip {
+            match structs.iter().find(|r| r.ident == *ident) {
+                Some(r) => check_roundtrip(l, r)?,
+                None => {
+                    return Err(syn::Error::new_spanned(
+                        ident,
+                        format!("struct `{ident}` not found in this module"),
+                    ))
+                }
+            }
+        }
+
+        items.push(generate_field_names_impl(l, structs)?);
+        if let Some(new_lhs) = generate_new_lhs(l)? {
+            items.extend(generate_from_tuple_impls(l, &new_lhs));
+            if traits.checked {
+                items.push(generate_try_from_corresponding_impl(l, &new_lhs));
+            }
+        }
+
+        // Split the struct into the tuples of structs of its `split` options
+        for idents in &options.split {
+            let mut parts = vec![];
+            for ident in idents {
+                let part = match structs.iter().find(|part| part.ident == *ident) {
+                    Some(part) => part,
+                    None => {
+                        return Err(syn::Error::new_spanned(
+                            ident,
+                            format!("struct `{ident}` not found in this module"),
+                        ))
+                    }
+                };
+                match generate_new_lhs(part)? {
+                    Some(new_lhs) => parts.push((part, new_lhs)),
+                    None => {
+                        return Err(syn::Error::new_spanned(
+                            ident,
+                            format!("struct `{ident}` can't be constructed, it needs to derive `Default` or have a field with a `default` option"),
+                        ))
+                    }
+                }
+            }
+            items.push(generate_split_corresponding_impl(l, &parts, structs)?);
+        }
+
+        for r in structs {
+            // The corresponding fields are the same for all implementations of this pair of structs
+            let pairs = get_corresponding_fields(l, r, structs)?;
+            if l != r {
+                if options.exhaustive && !nested_structs.contains(&&r.ident) {
+                    check_exhaustive(l, r, &pairs, structs)?;
+                }
+                items.push(generate_move_corresponding_impl(l, r, &pairs));
+                if let Some(new_lhs) = generate_new_lhs(l)? {
+                    items.push(generate_from_impl(l, r, &new_lhs));
+                    if has_derive(l, "Default") && has_derive(r, "Clone") {
+                        items.push(generate_from_cloned_impl(l, r, &new_lhs));
+                    }
+                }
+                if has_derive(r, "Clone") {
+                    items.push(generate_clone_corresponding_impl(l, r, &pairs));
+                }
+
+                // The optional traits of the left struct
+                if traits.fields {
+                    items.push(generate_corresponding_fields_impl(l, r, &pairs));
+                }
+                if traits.tracked {
+                    items.push(generate_move_corresponding_tracked_impl(l, r, &pairs));
+                }
+                if traits.changed {
+                    items.push(generate_move_corresponding_changed_impl(l, r, &pairs));
+                }
+                if traits.audited {
+                    items.push(generate_move_corresponding_audited_impl(l, r, &pairs));
+                }
+                if traits.undo {
+                    items.extend(generate_move_corresponding_with_undo_impl(l, r, &pairs));
+                }
+                if traits.checked {
+                    items.push(generate_move_corresponding_checked_impl(l, r, &pairs));
+                }
+                if traits.replace {
+                    items.push(generate_replace_corresponding_impl(l, r, &pairs, structs)?);
+                }
+                if traits.try_move {
+                    items.push(generate_try_move_corresponding_impl(l, r, &pairs));
+                }
+                if traits.take {
+                    items.push(generate_take_corresponding_impl(l, r, &pairs));
+                }
+                if traits.swap {
+                    items.push(generate_swap_corresponding_impl(l, r, &pairs));
+                }
+                if traits.eq {
+                    items.push(generate_eq_corresponding_impl(l, r, &pairs));
+                }
+            }
+
+            // Also fill a struct from another value of the same struct
+            if traits.fill {
+                items.push(generate_fill_corresponding_impl(l, r, &pairs));
+            }
+
+            // Also generate the diff between two values of the same struct
+            for p in &patches {
+                if *p != l && *p != r {
+                    items.push(generate_diff_corresponding_impl(l, r, p, &pairs));
+                }
+            }
+        }
+    }
+    Ok(())
 }
 
 /// Get the structs at top level of the module
@@ -169,137 +500,2294 @@ fn get_structs(items: &[Item]) -> Vec<syn::ItemStruct> {
         .collect()
 }
 
-/// Generate the `impl MoveCorresponding<Right> for Left` from two ItemStructs
-fn generate_move_corresponding_impl(l: &syn::ItemStruct, r: &syn::ItemStruct) -> Item {
-    let l_ident = &l.ident;
-    let r_ident = &r.ident;
+/// Remove the `#[corresponding(...)]` attributes from the structs and their fields,
+/// because the compiler doesn't know them
+fn remove_corresponding_attributes(items: &mut [Item]) {
+    for item in items {
+        if let Item::Struct(item_struct) = item {
+            item_struct
+                .attrs
+                .retain(|attribute| !attribute.path.is_ident("corresponding"));
+            for field in &mut item_struct.fields {
+                field
+                    .attrs
+                    .retain(|attribute| !attribute.path.is_ident("corresponding"));
+            }
+        }
+    }
+}
 
-    // Generate the statements
-    let mut statements: Vec<Stmt> = vec![];
-    for l_field in &l.fields {
-        for r_field in &r.fields {
-            let l_field_ident = &l_field.ident;
-            let r_field_ident = &r_field.ident;
-
-            if let Some(l_type) = get_type(&l_field.ty) {
-                if let Some(r_type) = get_type(&r_field.ty) {
-                    if l_field_ident == r_field_ident && l_type.ident == r_type.ident {
-                        match (l_type.option, r_type.option) {
-                            (false, false) => statements.push(parse_quote! { self. #l_field_ident = rhs. #r_field_ident ; }),
-                            (true, false) => statements.push(parse_quote! { self. #l_field_ident = Some ( rhs. #r_field_ident ) ; }),
-                            (false, true) => statements.push(parse_quote! { if let Some ( r ) = rhs. #r_field_ident { self. #l_field_ident = r } }),
-                            (true, true) => statements.push(parse_quote! { if rhs. #r_field_ident .is_some() { self. #l_field_ident = rhs. #r_field_ident } }),
-                        }
-                    }
+/// Parse the arguments of all `#[corresponding(...)]` attributes
+fn get_arguments(attributes: &[Attribute]) -> syn::Result<Vec<Argument>> {
+    let mut arguments = vec![];
+    for attribute in attributes {
+        if attribute.path.is_ident("corresponding") {
+            arguments.extend(
+                attribute.parse_args_with(Punctuated::<Argument, Token![,]>::parse_terminated)?,
+            );
+        }
+    }
+    Ok(arguments)
+}
+
+/// Get the string value of a `name = "value"` argument
+fn get_string_value(argument: &Argument) -> syn::Result<String> {
+    match &argument.value {
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Str(lit_str),
+            ..
+        })) => Ok(lit_str.value()),
+        Some(value) => Err(syn::Error::new_spanned(value, "expected a string literal")),
+        None => Err(syn::Error::new_spanned(
+            &argument.name,
+            format!("expected `{} = \"...\"`", argument.name),
+        )),
+    }
+}
+
+/// Get the value of a `name = value` argument
+fn get_value(argument: &Argument) -> syn::Result<&Expr> {
+    argument.value.as_ref().ok_or_else(|| {
+        syn::Error::new_spanned(
+            &argument.name,
+            format!("expected `{} = ...`", argument.name),
+        )
+    })
+}
+
+/// Check that a flag argument has no value
+fn get_flag(argument: &Argument) -> syn::Result<bool> {
+    match (&argument.value, &argument.arguments) {
+        (Some(value), _) => Err(syn::Error::new_spanned(
+            value,
+            format!("expected `{}` without a value", argument.name),
+        )),
+        (None, Some(_)) => Err(syn::Error::new_spanned(
+            &argument.name,
+            format!("expected `{}` without arguments", argument.name),
+        )),
+        (None, None) => Ok(true),
+    }
+}
+
+/// Parse the functions of a `compute = path::to::function` or
+/// `compute(Struct = path::to::function, ...)` argument
+fn get_compute_functions(argument: &Argument) -> syn::Result<Vec<ComputeFunction>> {
+    match &argument.arguments {
+        Some(arguments) => arguments
+            .iter()
+            .map(|argument| {
+                Ok(ComputeFunction {
+                    source: Some(argument.name.clone()),
+                    function: get_value(argument)?.clone(),
+                })
+            })
+            .collect(),
+        None => Ok(vec![ComputeFunction {
+            source: None,
+            function: get_value(argument)?.clone(),
+        }]),
+    }
+}
+
+/// Get the function computing the value of a field from the right struct.
+/// A function for the right struct itself wins over a function for every struct.
+fn get_compute_function<'a>(options: &'a FieldOptions, r: &syn::ItemStruct) -> Option<&'a Expr> {
+    let find = |source: Option<&Ident>| {
+        options
+            .compute
+            .iter()
+            .find(|compute| compute.source.as_ref() == source)
+    };
+    find(Some(&r.ident))
+        .or_else(|| find(None))
+        .map(|compute| &compute.function)
+}
+
+/// Parse the strategy of a `merge = "..."` or `merge = path::to::function` argument
+fn get_merge_strategy(argument: &Argument) -> syn::Result<MergeStrategy> {
+    let value = get_value(argument)?;
+    if let Expr::Path(_) = value {
+        return Ok(MergeStrategy::Custom(Box::new(value.clone())));
+    }
+    match get_string_value(argument)?.as_str() {
+        "append" => Ok(MergeStrategy::Append),
+        "extend" => Ok(MergeStrategy::Extend),
+        "sum" => Ok(MergeStrategy::Sum),
+        "max" => Ok(MergeStrategy::Max),
+        _ => Err(syn::Error::new_spanned(
+            value,
+            "expected `append`, `extend`, `sum`, `max` or a path to a function",
+        )),
+    }
+}
+
+/// Parse the path of a `from = "..."` argument
+fn get_source_path(argument: &Argument) -> syn::Result<SourcePath> {
+    let name = get_string_value(argument)?;
+    let value = get_value(argument)?.clone();
+    let error = || {
+        syn::Error::new_spanned(
+            &value,
+            "expected a path like `address.city` or `profile?.avatar`",
+        )
+    };
+
+    let mut segments = vec![];
+    for segment in name.split('.') {
+        let (field_name, option) = match segment.strip_suffix('?') {
+            Some(field_name) => (field_name, true),
+            None => (segment, false),
+        };
+        let ident = syn::parse_str::<Ident>(field_name).map_err(|_| error())?;
+        segments.push((ident, option));
+    }
+    if let Some((_, true)) = segments.last() {
+        return Err(error());
+    }
+
+    Ok(SourcePath {
+        value,
+        name,
+        segments,
+    })
+}
+
+/// Get the options of a struct from its `#[corresponding(...)]` attributes
+fn get_struct_options(item_struct: &syn::ItemStruct) -> syn::Result<StructOptions> {
+    let mut options = StructOptions::default();
+    for argument in get_arguments(&item_struct.attrs)? {
+        match argument.name.to_string().as_str() {
+            "exhaustive" => options.exhaustive = get_flag(&argument)?,
+            "patch" => options.patch = get_flag(&argument)?,
+            "roundtrip" => match get_value(&argument)? {
+                Expr::Path(expr_path) if expr_path.path.get_ident().is_some() => options
+                    .roundtrip
+                    .extend(expr_path.path.get_ident().cloned()),
+                value => {
+                    return Err(syn::Error::new_spanned(
+                        value,
+                        "expected the name of a struct in this module",
+                    ))
                 }
+            },
+            "split" => options.split.push(get_split_structs(&argument)?),
+            "take" => options.traits.take = get_flag(&argument)?,
+            "swap" => options.traits.swap = get_flag(&argument)?,
+            "eq" => options.traits.eq = get_flag(&argument)?,
+            "tracked" => options.traits.tracked = get_flag(&argument)?,
+            "changed" => options.traits.changed = get_flag(&argument)?,
+            "audited" => options.traits.audited = get_flag(&argument)?,
+            "undo" => options.traits.undo = get_flag(&argument)?,
+            "fields" => options.traits.fields = get_flag(&argument)?,
+            "checked" => options.traits.checked = get_flag(&argument)?,
+            "fill" => options.traits.fill = get_flag(&argument)?,
+            "replace" => options.traits.replace = get_flag(&argument)?,
+            "try_move" => options.traits.try_move = get_flag(&argument)?,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &argument.name,
+                    "unknown corresponding option",
+                ))
             }
         }
     }
+    Ok(options)
+}
 
-    // Generate the impl
-    parse_quote! {
-        impl ::corresponding::MoveCorresponding< #r_ident > for #l_ident {
-            #[inline]
-            fn move_corresponding(&mut self, rhs: #r_ident ) {
-                #(#statements)*
+/// Get the names of the structs of the `split` option, a tuple of two up to four structs
+fn get_split_structs(argument: &Argument) -> syn::Result<Vec<Ident>> {
+    let error = |value: &Expr| {
+        syn::Error::new_spanned(
+            value,
+            "expected a tuple of two up to four structs in this module",
+        )
+    };
+    let elems = match get_value(argument)? {
+        Expr::Tuple(tuple) if (2..=4).contains(&tuple.elems.len()) => &tuple.elems,
+        value => return Err(error(value)),
+    };
+    elems
+        .iter()
+        .map(|elem| match elem {
+            Expr::Path(expr_path) => expr_path
+                .path
+                .get_ident()
+                .cloned()
+                .ok_or_else(|| error(elem)),
+            elem => Err(error(elem)),
+        })
+        .collect()
+}
+
+/// Get the options of a field from its `#[corresponding(...)]` attributes
+fn get_field_options(field: &Field) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+    for argument in get_arguments(&field.attrs)? {
+        match argument.name.to_string().as_str() {
+            "option" => {
+                options.option = Some(match get_string_value(&argument)?.as_str() {
+                    "some" => OptionPolicy::Some,
+                    "overwrite" => OptionPolicy::Overwrite,
+                    "default" => OptionPolicy::Default,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &argument.value,
+                            "expected `some`, `overwrite` or `default`",
+                        ))
+                    }
+                })
+            }
+            "audit" => options.audit = Some(get_value(&argument)?.clone()),
+            "skip" => options.skip = get_flag(&argument)?,
+            "default" => options.default = Some(get_value(&argument)?.clone()),
+            "compute" => options.compute.extend(get_compute_functions(&argument)?),
+            "flatten" => options.flatten = get_flag(&argument)?,
+            "from" => options.from = Some(get_source_path(&argument)?),
+            "merge" => {
+                if get_type(&field.ty).is_some_and(|ty| ty.option) {
+                    return Err(syn::Error::new_spanned(
+                        &argument.name,
+                        "option `merge` is not supported on a field of type `Option<T>`",
+                    ));
+                }
+                options.merge = Some(get_merge_strategy(&argument)?)
+            }
+            "skip_if" => options.skip_if = Some(get_value(&argument)?.clone()),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &argument.name,
+                    "unknown corresponding option",
+                ))
             }
         }
     }
+    Ok(options)
 }
 
-/// Generate the `impl CloneCorresponding<Right> for Left` from two ItemStructs
-fn generate_clone_corresponding_impl(l: &syn::ItemStruct, r: &syn::ItemStruct) -> Item {
-    let l_ident = &l.ident;
-    let r_ident = &r.ident;
+/// Get all pairs of fields with the same name and type of the left and right struct.
+/// The computed fields of the left struct come first, so they are computed before
+/// any field of the right struct is moved.
+fn get_corresponding_fields<'a>(
+    l: &'a syn::ItemStruct,
+    r: &'a syn::ItemStruct,
+    structs: &'a [syn::ItemStruct],
+) -> syn::Result<Vec<FieldPair<'a>>> {
+    let l_fields = get_flat_fields(l, structs)?;
+    let r_fields = get_flat_fields(r, structs)?;
+    let mut pairs = vec![];
+    for l_field in &l_fields {
+        if let Some(l_type) = get_type(&l_field.field.ty) {
+            let l_options = get_field_options(l_field.field)?;
+            if let (false, Some(compute)) = (l_options.skip, get_compute_function(&l_options, r)) {
+                pairs.push(FieldPair {
+                    l_field: l_field.clone(),
+                    source: Source::Compute(Box::new(compute.clone())),
+                    r_type: l_type.clone(),
+                    l_type,
+                    skip_if: l_options.skip_if.clone(),
+                    l_options,
+                    policy: OptionPolicy::default(),
+                });
+            }
+        }
+    }
+    for l_field in &l_fields {
+        let l_options = get_field_options(l_field.field)?;
+        if let (false, Some(from)) = (l_options.skip, &l_options.from) {
+            if let Some((source, r_type)) = get_source_field(from, r, structs)? {
+                let l_type = match get_type(&l_field.field.ty) {
+                    Some(l_type) if l_type.ident == r_type.ident => l_type,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &from.value,
+                            format!(
+                                "field `{}` of `{}` has another type than field `{}`",
+                                from.name,
+                                r.ident,
+                                get_path_name(l_field)
+                            ),
+                        ))
+                    }
+                };
+                let r_options = get_field_options(match &source {
+                    Source::Field(r_field) => r_field.field,
+                    Source::Nested(nested) => nested.field,
+                    Source::Compute(_) => unreachable!(),
+                })?;
+                if r_options.skip {
+                    continue;
+                }
 
-    // Generate the statements
-    let mut statements: Vec<Stmt> = vec![];
-    for l_field in &l.fields {
-        for r_field in &r.fields {
-            let l_field_ident = &l_field.ident;
-            let r_field_ident = &r_field.ident;
-
-            if let Some(l_type) = get_type(&l_field.ty) {
-                if let Some(r_type) = get_type(&r_field.ty) {
-                    if l_field_ident == r_field_ident && l_type.ident == r_type.ident {
-                        match (l_type.option, r_type.option) {
-                            (false, false) => statements.push(parse_quote! { self. #l_field_ident = rhs. #r_field_ident .clone() ; }),
-                            (true, false) => statements.push(parse_quote! { self. #l_field_ident = Some ( rhs. #r_field_ident .clone()) ; }),
-                            (false, true) => statements.push(parse_quote! { if rhs. #r_field_ident .is_some() { self. #l_field_ident = rhs. #r_field_ident .clone().unwrap() } }),
-                            (true, true) => statements.push(parse_quote! { if rhs. #r_field_ident .is_some() { self. #l_field_ident = rhs. #r_field_ident .clone() } }),
+                // The option policy and predicate of the target field win over the ones of the source field
+                let policy = l_options.option.or(r_options.option).unwrap_or_default();
+                let skip_if = l_options.skip_if.clone().or(r_options.skip_if);
+
+                pairs.push(FieldPair {
+                    l_field: l_field.clone(),
+                    source,
+                    l_type,
+                    r_type,
+                    l_options,
+                    policy,
+                    skip_if,
+                });
+            }
+        }
+    }
+    for l_field in &l_fields {
+        for r_field in &r_fields {
+            if let Some(l_type) = get_type(&l_field.field.ty) {
+                if let Some((source, r_type)) = get_field_source(&l_type, r_field) {
+                    if l_field.field.ident == r_field.field.ident {
+                        let l_options = get_field_options(l_field.field)?;
+                        let r_options = get_field_options(r_field.field)?;
+                        if l_options.skip
+                            || r_options.skip
+                            || !l_options.compute.is_empty()
+                            || l_options.from.is_some()
+                            || is_moved_whole(&pairs, l_field, r_field)
+                        {
+                            continue;
                         }
+
+                        // The option policy and predicate of the target field win over the ones of the source field.
+                        // A `Result<T, E>` source field overwrites an `Option<T>` target field by default.
+                        let policy = match (&source, l_options.option.or(r_options.option)) {
+                            (_, Some(policy)) => policy,
+                            (
+                                Source::Nested(NestedField {
+                                    result: Some(_), ..
+                                }),
+                                None,
+                            ) => OptionPolicy::Overwrite,
+                            (_, None) => OptionPolicy::default(),
+                        };
+                        let skip_if = l_options.skip_if.clone().or(r_options.skip_if);
+
+                        pairs.push(FieldPair {
+                            l_field: l_field.clone(),
+                            source,
+                            l_type,
+                            r_type,
+                            l_options,
+                            policy,
+                            skip_if,
+                        });
                     }
                 }
             }
         }
     }
 
-    // Generate the impl
-    parse_quote! {
-        impl ::corresponding::CloneCorresponding< #r_ident > for #l_ident {
-            #[inline]
-            fn clone_corresponding(&mut self, rhs: & #r_ident ) {
-                #(#statements)*
+    // Moving a field out of an `Option` moves the whole `Option`, so the fields read through the
+    // same `Option` are taken out of it, except for the last one
+    for i in 0..pairs.len() {
+        let (pair, later_pairs) = pairs[i..].split_first_mut().unwrap();
+        if let Source::Nested(nested) = &mut pair.source {
+            nested.take = later_pairs.iter().any(|later_pair| {
+                matches!(&later_pair.source, Source::Nested(later) if later.prefix == nested.prefix)
+            });
+        }
+    }
+
+    // A field of the right struct can only be moved once, so two fields can't read the same field,
+    // or a field inside of it, except for the fields read through the same `Option`
+    for (i, pair) in pairs.iter().enumerate() {
+        for other in &pairs[..i] {
+            let (path, other_path) = match (&pair.source, &other.source) {
+                (Source::Nested(nested), Source::Nested(other_nested))
+                    if nested.prefix == other_nested.prefix =>
+                {
+                    continue
+                }
+                (source, other_source) => {
+                    match (get_read_path(source), get_read_path(other_source)) {
+                        (Some(path), Some(other_path)) => (path, other_path),
+                        _ => continue,
+                    }
+                }
+            };
+            let outer = if is_inside(path, other_path) {
+                other_path
+            } else if path == other_path || is_inside(other_path, path) {
+                path
+            } else {
+                continue;
+            };
+
+            // Point at the field with the `from` option
+            let field = if other.l_options.from.is_some() && pair.l_options.from.is_none() {
+                other.l_field.field
+            } else {
+                pair.l_field.field
+            };
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "fields `{}` and `{}` both read field `{}` of `{}`, which can only be moved once",
+                    get_path_name(&other.l_field),
+                    get_path_name(&pair.l_field),
+                    outer
+                        .iter()
+                        .map(|ident| ident.unraw().to_string())
+                        .collect::<Vec<_>>()
+                        .join("."),
+                    r.ident
+                ),
+            ));
+        }
+    }
+    Ok(pairs)
+}
+
+/// Get the source of a field of the left struct in a field of the right struct with the same type, with its type.
+/// A field of type `Result<T, E>` is the source of a field of type `T` or `Option<T>`, like an `Option<T>`.
+fn get_field_source<'a>(
+    l_type: &OptionType,
+    r_field: &FlatField<'a>,
+) -> Option<(Source<'a>, OptionType)> {
+    let r_type = get_type(&r_field.field.ty)?;
+    if r_type.ident == l_type.ident {
+        return Some((Source::Field(r_field.clone()), r_type));
+    }
+
+    let (r_type, error) = get_result_type(&r_field.field.ty)?;
+    if r_type.ident != l_type.ident {
+        return None;
+    }
+    let nested = NestedField {
+        field: r_field.field,
+        option: false,
+        prefix: r_field.path.clone(),
+        steps: vec![],
+        name: get_path_name(r_field),
+        result: Some(Box::new(error)),
+        take: false,
+    };
+    Some((Source::Nested(nested), r_type))
+}
+
+/// Check whether a field is inside a flattened field that is already moved as a whole
+fn is_moved_whole(pairs: &[FieldPair], l_field: &FlatField, r_field: &FlatField) -> bool {
+    pairs.iter().any(|pair| match &pair.source {
+        Source::Field(pair_r_field) => {
+            is_inside(&l_field.path, &pair.l_field.path)
+                || is_inside(&r_field.path, &pair_r_field.path)
+        }
+        _ => false,
+    })
+}
+
+/// Get the path of the field of the right struct that a source reads, up to the first `Option` field
+fn get_read_path<'b>(source: &'b Source) -> Option<&'b Punctuated<Ident, Token![.]>> {
+    match source {
+        Source::Field(r_field) => Some(&r_field.path),
+        Source::Nested(nested) => Some(&nested.prefix),
+        Source::Compute(_) => None,
+    }
+}
+
+/// Check whether a path is inside another path, like `address.city` is inside `address`
+fn is_inside(path: &Punctuated<Ident, Token![.]>, outer: &Punctuated<Ident, Token![.]>) -> bool {
+    outer.len() < path.len() && outer.iter().zip(path).all(|(a, b)| a == b)
+}
+
+/// Find the field at the path of the `from` option in the right struct, with its type.
+/// Return `None` when the right struct doesn't have the path.
+fn get_source_field<'a>(
+    from: &SourcePath,
+    r: &'a syn::ItemStruct,
+    structs: &'a [syn::ItemStruct],
+) -> syn::Result<Option<(Source<'a>, OptionType)>> {
+    let error = |message: String| Err(syn::Error::new_spanned(&from.value, message));
+    let find_field = |item_struct: &'a syn::ItemStruct, ident: &Ident| {
+        item_struct
+            .fields
+            .iter()
+            .find(|field| field.ident.as_ref() == Some(ident))
+    };
+
+    // Follow the path through the structs of the module
+    let mut item_struct = r;
+    let mut path = Punctuated::new();
+    let mut paths = vec![];
+    let ((leaf_ident, _), segments) = from.segments.split_last().unwrap();
+    for (ident, option) in segments {
+        let field = match find_field(item_struct, ident) {
+            Some(field) => field,
+            None => return Ok(None),
+        };
+        path.push(ident.clone());
+
+        let ty = get_type(&field.ty);
+        match &ty {
+            Some(ty) if ty.option && !option => {
+                return error(format!(
+                    "field `{ident}` of `{}` is an `Option`, expected `{ident}?`",
+                    item_struct.ident
+                ))
             }
+            Some(ty) if !ty.option && *option => {
+                return error(format!(
+                    "field `{ident}` of `{}` is not an `Option`",
+                    item_struct.ident
+                ))
+            }
+            _ => {}
+        }
+        if *option {
+            paths.push(std::mem::take(&mut path));
         }
+
+        item_struct = match ty.and_then(|ty| structs.iter().find(|s| s.ident == ty.ident)) {
+            Some(item_struct) => item_struct,
+            None => {
+                return error(format!(
+                    "the type of field `{ident}` of `{}` is not a struct in this module",
+                    item_struct.ident
+                ))
+            }
+        };
+    }
+
+    let field = match find_field(item_struct, leaf_ident) {
+        Some(field) => field,
+        None => return Ok(None),
+    };
+    let r_type = match get_type(&field.ty) {
+        Some(r_type) => r_type,
+        None => return Ok(None),
+    };
+    path.push(leaf_ident.clone());
+
+    if paths.is_empty() {
+        Ok(Some((Source::Field(FlatField { field, path }), r_type)))
+    } else {
+        // The value of a field reached through an `Option` is an `Option` itself
+        paths.push(path);
+        let nested = NestedField {
+            field,
+            option: r_type.option,
+            prefix: paths.remove(0),
+            steps: paths,
+            name: from.name.clone(),
+            result: None,
+            take: false,
+        };
+        Ok(Some((
+            Source::Nested(nested),
+            OptionType {
+                option: true,
+                ..r_type
+            },
+        )))
     }
 }
 
-/// Check whether the given struct has `#[derive(...)]` attribute
-fn has_derive(l: &syn::ItemStruct, derive: &str) -> bool {
-    for attribute in l.clone().attrs {
-        if let Some(PathSegment { ident, .. }) = attribute.path.segments.first() {
-            if ident.to_string().as_str() == "derive" {
-                for token_tree in attribute.tokens {
-                    if let TokenTree::Group(group) = token_tree {
-                        for token_tree2 in group.stream() {
-                            if let TokenTree::Ident(ident) = token_tree2 {
-                                if ident.to_string().as_str() == derive {
-                                    return true;
-                                }
+/// Get the structs of the flattened fields and of the fields on the paths of `from` options,
+/// which are parts of other structs
+fn get_nested_structs(structs: &[syn::ItemStruct]) -> syn::Result<Vec<&Ident>> {
+    let find_struct = |ty: &Type| {
+        get_type(ty).and_then(|ty| {
+            structs
+                .iter()
+                .find(|item_struct| item_struct.ident == ty.ident)
+        })
+    };
+    let mut nested_structs = vec![];
+    for item_struct in structs {
+        for field in &item_struct.fields {
+            let options = get_field_options(field)?;
+            if options.flatten {
+                nested_structs.extend(find_struct(&field.ty).map(|nested| &nested.ident));
+            }
+
+            // Follow the path through every struct that has its first field
+            if let Some(from) = options.from {
+                let (_, segments) = from.segments.split_last().unwrap();
+                for r in structs {
+                    let mut item_struct = r;
+                    for (ident, _) in segments {
+                        match item_struct
+                            .fields
+                            .iter()
+                            .find(|field| field.ident.as_ref() == Some(ident))
+                            .and_then(|field| find_struct(&field.ty))
+                        {
+                            Some(nested) => {
+                                nested_structs.push(&nested.ident);
+                                item_struct = nested;
                             }
+                            None => break,
                         }
                     }
                 }
             }
         }
     }
-    false
+    Ok(nested_structs)
 }
 
-/// Generate `impl From<Right> for Left`
-/// Just construct a new object by using the Default trait
-/// and move the corresponding fields
-fn generate_from_impl(l: &syn::ItemStruct, r: &syn::ItemStruct) -> Item {
-    let l_ident = &l.ident;
-    let r_ident = &r.ident;
+/// Check that all fields of the left struct have a corresponding field in the right struct,
+/// except for the skipped fields and the fields with a default value.
+/// Instead of a flattened field, the fields of its struct are checked,
+/// unless the flattened field itself has a corresponding field.
+fn check_exhaustive(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+    structs: &[syn::ItemStruct],
+) -> syn::Result<()> {
+    for l_field in get_flat_fields(l, structs)? {
+        let l_options = get_field_options(l_field.field)?;
+        if !l_options.skip
+            && !l_options.flatten
+            && l_options.default.is_none()
+            && !pairs.iter().any(|pair| {
+                pair.l_field.path == l_field.path || is_inside(&l_field.path, &pair.l_field.path)
+            })
+        {
+            return Err(syn::Error::new_spanned(
+                l_field.field,
+                format!(
+                    "field `{}` of exhaustive struct `{}` has no corresponding field in `{}`",
+                    get_path_name(&l_field),
+                    l.ident,
+                    r.ident
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check that all fields of the left struct are moved to the right struct and back
+/// without losing data, because the right struct has fields with the same names and types.
+/// Skipped fields are not moved, and fields with an option changing the moved value
+/// don't keep their value, so they are not allowed on either side.
+fn check_roundtrip(l: &syn::ItemStruct, r: &syn::ItemStruct) -> syn::Result<()> {
+    for l_field in &l.fields {
+        let l_field_name = get_field_name(l_field);
+        let l_options = get_field_options(l_field)?;
+        let r_field = r
+            .fields
+            .iter()
+            .find(|r_field| r_field.ident == l_field.ident);
+        let error = if l_options.skip {
+            "the field is skipped".to_string()
+        } else if let Some(option) = get_lossy_option(&l_options) {
+            format!("the field has the `{option}` option")
+        } else if let Some(r_field) = r_field {
+            let r_options = get_field_options(r_field)?;
+            if r_options.skip {
+                format!("the field is skipped in `{}`", r.ident)
+            } else if let Some(option) = get_lossy_option(&r_options) {
+                format!("the field has the `{option}` option in `{}`", r.ident)
+            } else if r_field.ty != l_field.ty {
+                format!(
+                    "the field has type `{}` in `{}`",
+                    r_field.ty.to_token_stream(),
+                    r.ident
+                )
+            } else {
+                continue;
+            }
+        } else {
+            format!("`{}` has no field `{}`", r.ident, l_field_name)
+        };
+        return Err(syn::Error::new_spanned(
+            l_field,
+            format!(
+                "field `{}` of `{}` doesn't roundtrip to `{}`: {}",
+                l_field_name, l.ident, r.ident, error
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Get the name of the option of a field that changes the moved value, so the value
+/// of the field is not kept when it is moved
+fn get_lossy_option(options: &FieldOptions) -> Option<&'static str> {
+    if options.skip_if.is_some() {
+        Some("skip_if")
+    } else if options.merge.is_some() {
+        Some("merge")
+    } else if !options.compute.is_empty() {
+        Some("compute")
+    } else if options.from.is_some() {
+        Some("from")
+    } else if matches!(
+        options.option,
+        Some(OptionPolicy::Overwrite | OptionPolicy::Default)
+    ) {
+        Some("option")
+    } else {
+        None
+    }
+}
 
+/// Generates the statement writing a value to the field of `self`
+type Write = fn(&FieldPair, Expr) -> Stmt;
+
+/// Generate the statement moving (or cloning) the field of `rhs` to the field of `self`.
+/// The `write` function generates the statement writing the moved value to `self`.
+/// Nothing is written when the `skip_if` predicate is true for the value of the field of `rhs`.
+fn generate_statement(pair: &FieldPair, access: Access, write: Write) -> Stmt {
+    let statement = generate_write_statement(pair, access, write);
+    let condition = match generate_skip_condition(pair) {
+        Some(condition) => condition,
+        None => return statement,
+    };
     parse_quote! {
-        impl ::std::convert::From< #r_ident > for #l_ident {
-            #[inline]
-            fn from(rhs: #r_ident ) -> Self {
-                use ::corresponding::MoveCorresponding;
-                let mut lhs = Self::default();
-                lhs.move_corresponding(rhs);
-                lhs
+        if !( #condition ) {
+            #statement
+        }
+    }
+}
+
+/// Generate the condition of the `skip_if` predicate on the field of `rhs`, which is borrowed.
+/// The predicate of a computed field is checked on the computed value instead.
+fn generate_skip_condition(pair: &FieldPair) -> Option<Expr> {
+    let skip_if = pair.skip_if.as_ref()?;
+    match &pair.source {
+        Source::Field(r_field) => {
+            let r_field_ident = &r_field.path;
+            if pair.r_type.option {
+                Some(parse_quote! { rhs. #r_field_ident .as_ref().is_some_and(|r| #skip_if (r)) })
+            } else {
+                Some(parse_quote! { #skip_if (&rhs. #r_field_ident ) })
+            }
+        }
+        Source::Nested(nested) => {
+            let value = generate_nested_value(nested, None);
+            Some(parse_quote! { #value .is_some_and(|r| #skip_if (r)) })
+        }
+        Source::Compute(_) => None,
+    }
+}
+
+/// Generate the statement moving (or cloning) the field of `rhs` to the field of `self`,
+/// without checking the `skip_if` predicate of the field of `rhs`.
+fn generate_write_statement(pair: &FieldPair, access: Access, write: Write) -> Stmt {
+    let r_field_ident = match &pair.source {
+        Source::Field(r_field) => &r_field.path,
+        Source::Nested(nested) => {
+            let value = generate_nested_value(nested, Some(access));
+            return match (pair.l_type.option, pair.policy) {
+                (false, OptionPolicy::Default) => {
+                    write(pair, parse_quote! { #value .unwrap_or_default() })
+                }
+                (false, _) => {
+                    let write = write(pair, parse_quote! { r });
+                    parse_quote! { if let Some ( r ) = #value { #write } }
+                }
+                (true, OptionPolicy::Some) => {
+                    let write = write(pair, parse_quote! { Some ( r ) });
+                    parse_quote! { if let Some ( r ) = #value { #write } }
+                }
+                (true, _) => write(pair, value),
+            };
+        }
+        Source::Compute(compute) => {
+            let rhs: Expr = match access {
+                Access::Move => parse_quote! { &rhs },
+                Access::Clone | Access::Take => parse_quote! { rhs },
+            };
+            let skip_if = match &pair.skip_if {
+                Some(skip_if) => skip_if,
+                None => return write(pair, parse_quote! { #compute ( #rhs ) }),
+            };
+            let condition: Expr = if pair.l_type.option {
+                parse_quote! { computed.as_ref().is_some_and(|r| #skip_if (r)) }
+            } else {
+                parse_quote! { #skip_if (&computed) }
+            };
+            let write = write(pair, parse_quote! { computed });
+            return parse_quote! {
+                {
+                    let computed = #compute ( #rhs );
+                    if !( #condition ) {
+                        #write
+                    }
+                }
+            };
+        }
+    };
+
+    // The value of the source field, and the unwrapped value when the source field is `Some(r)`
+    let (value, some, unwrapped): (Expr, Expr, Expr) = match access {
+        Access::Move => (
+            parse_quote! { rhs. #r_field_ident },
+            parse_quote! { rhs. #r_field_ident },
+            parse_quote! { r },
+        ),
+        Access::Clone => (
+            parse_quote! { rhs. #r_field_ident .clone() },
+            parse_quote! { &rhs. #r_field_ident },
+            parse_quote! { r.clone() },
+        ),
+        Access::Take if pair.r_type.option => (
+            parse_quote! { rhs. #r_field_ident .take() },
+            parse_quote! { rhs. #r_field_ident .take() },
+            parse_quote! { r },
+        ),
+        Access::Take => (
+            parse_quote! { ::std::mem::take(&mut rhs. #r_field_ident ) },
+            parse_quote! { rhs. #r_field_ident },
+            parse_quote! { r },
+        ),
+    };
+
+    match (pair.l_type.option, pair.r_type.option, pair.policy) {
+        (false, false, _) => write(pair, value),
+        (true, false, _) => write(pair, parse_quote! { Some ( #value ) }),
+        (false, true, OptionPolicy::Default) => {
+            write(pair, parse_quote! { #value .unwrap_or_default() })
+        }
+        (false, true, _) => {
+            let write = write(pair, unwrapped);
+            parse_quote! { if let Some ( r ) = #some { #write } }
+        }
+        (true, true, OptionPolicy::Some) => {
+            let write = write(pair, value);
+            parse_quote! { if rhs. #r_field_ident .is_some() { #write } }
+        }
+        (true, true, _) => write(pair, value),
+    }
+}
+
+/// Write the value to the field of `self` and add the name of the field to the written fields.
+/// Return a conflict when the field is already written with another value.
+fn write_field_checked(pair: &FieldPair, value: Expr) -> Stmt {
+    let l_field_ident = &pair.l_field.path;
+    let l_field_name = get_path_name(&pair.l_field);
+
+    // The values of a field with a merge strategy are combined, so they don't conflict
+    if pair.l_options.merge.is_some() {
+        let write = write_field(pair, value);
+        return parse_quote! {
+            {
+                #write
+                written.push( #l_field_name );
+            }
+        };
+    }
+    parse_quote! {
+        {
+            let value = #value ;
+            if written.contains(& #l_field_name ) && self. #l_field_ident != value {
+                return ::std::result::Result::Err(::corresponding::Conflict { field: #l_field_name });
+            }
+            self. #l_field_ident = value;
+            written.push( #l_field_name );
+        }
+    }
+}
+
+/// Generate the value of a nested field, following the `Option` fields on its path.
+/// The value is an `Option`, which is borrowed as `Option<&T>` when no access is given.
+fn generate_nested_value(nested: &NestedField, access: Option<Access>) -> Expr {
+    let prefix = &nested.prefix;
+    let access = match access {
+        Some(Access::Move) if nested.take => Some(Access::Take),
+        access => access,
+    };
+    let mut value: Expr = match access {
+        Some(Access::Move) => parse_quote! { rhs. #prefix },
+        Some(Access::Clone) | None => parse_quote! { rhs. #prefix .as_ref() },
+        Some(Access::Take) => parse_quote! { rhs. #prefix .as_mut() },
+    };
+    if nested.result.is_some() {
+        value = parse_quote! { ::std::result::Result::ok( #value ) };
+    }
+
+    // The field of type `Result<T, E>` itself is used when there are no more steps
+    let (leaf, steps) = match nested.steps.split_last() {
+        Some(steps) => steps,
+        None => {
+            return match access {
+                Some(Access::Move) | None => value,
+                Some(Access::Clone) => parse_quote! { #value .cloned() },
+                Some(Access::Take) => parse_quote! { #value .map(::std::mem::take) },
+            }
+        }
+    };
+    for step in steps {
+        value = match access {
+            Some(Access::Move) => parse_quote! { #value .and_then(|r| r. #step ) },
+            Some(Access::Clone) | None => parse_quote! { #value .and_then(|r| r. #step .as_ref()) },
+            Some(Access::Take) => parse_quote! { #value .and_then(|r| r. #step .as_mut()) },
+        };
+    }
+
+    match (access, nested.option) {
+        (Some(Access::Move), false) => parse_quote! { #value .map(|r| r. #leaf ) },
+        (Some(Access::Move), true) => parse_quote! { #value .and_then(|r| r. #leaf ) },
+        (Some(Access::Clone), false) => parse_quote! { #value .map(|r| r. #leaf .clone()) },
+        (Some(Access::Clone), true) => parse_quote! { #value .and_then(|r| r. #leaf .clone()) },
+        (Some(Access::Take), false) => {
+            parse_quote! { #value .map(|r| ::std::mem::take(&mut r. #leaf )) }
+        }
+        (Some(Access::Take), true) => parse_quote! { #value .and_then(|r| r. #leaf .take()) },
+        (None, false) => parse_quote! { #value .map(|r| &r. #leaf ) },
+        (None, true) => parse_quote! { #value .and_then(|r| r. #leaf .as_ref()) },
+    }
+}
+
+/// Write the value to the field of `self`, or combine it with the current value when the field
+/// has a merge strategy
+fn write_field(pair: &FieldPair, value: Expr) -> Stmt {
+    let l_field_ident = &pair.l_field.path;
+    match &pair.l_options.merge {
+        None => parse_quote! { self. #l_field_ident = #value ; },
+        Some(MergeStrategy::Append) => parse_quote! {
+            {
+                let mut value = #value ;
+                self. #l_field_ident .append(&mut value);
+            }
+        },
+        Some(MergeStrategy::Extend) => parse_quote! { self. #l_field_ident .extend( #value ); },
+        Some(MergeStrategy::Sum) => parse_quote! { self. #l_field_ident += #value ; },
+        Some(MergeStrategy::Max) => parse_quote! {
+            {
+                let value = #value ;
+                if value > self. #l_field_ident {
+                    self. #l_field_ident = value;
+                }
+            }
+        },
+        Some(MergeStrategy::Custom(merge)) => {
+            parse_quote! { #merge (&mut self. #l_field_ident , #value ); }
+        }
+    }
+}
+
+/// Write the value to the field of `self` only when the field is `None`, or equal to its default value
+/// when it is not an `Option`
+fn write_field_filled(pair: &FieldPair, value: Expr) -> Stmt {
+    let l_field_ident = &pair.l_field.path;
+    let l_field_type = &pair.l_field.field.ty;
+    if pair.l_type.option {
+        parse_quote! {
+            if self. #l_field_ident .is_none() {
+                self. #l_field_ident = #value ;
+            }
+        }
+    } else {
+        parse_quote! {
+            if self. #l_field_ident == < #l_field_type as ::std::default::Default >::default() {
+                self. #l_field_ident = #value ;
+            }
+        }
+    }
+}
+
+/// Write the value to the field of `lhs`
+fn write_lhs_field(pair: &FieldPair, value: Expr) -> Stmt {
+    let l_field_ident = &pair.l_field.path;
+    parse_quote! { lhs. #l_field_ident = #value ; }
+}
+
+/// Write the value to the field of `self` and add the name of the field to the changes
+fn write_field_tracked(pair: &FieldPair, value: Expr) -> Stmt {
+    let write = write_field(pair, value);
+    let l_field_name = get_path_name(&pair.l_field);
+    parse_quote! {
+        {
+            #write
+            changes.push( #l_field_name );
+        }
+    }
+}
+
+/// Write the value to the field of `self` only when it differs from the current value,
+/// and add the name of the field to the changes. A field with a merge strategy is changed
+/// when the merged value differs from the previous value.
+fn write_field_changed(pair: &FieldPair, value: Expr) -> Stmt {
+    let l_field_ident = &pair.l_field.path;
+    let l_field_name = get_path_name(&pair.l_field);
+    if pair.l_options.merge.is_some() {
+        let write = write_field(pair, value);
+        return parse_quote! {
+            {
+                let previous = self. #l_field_ident .clone();
+                #write
+                if self. #l_field_ident != previous {
+                    changes.push( #l_field_name );
+                }
+            }
+        };
+    }
+    parse_quote! {
+        {
+            let value = #value ;
+            if self. #l_field_ident != value {
+                self. #l_field_ident = value;
+                changes.push( #l_field_name );
+            }
+        }
+    }
+}
+
+/// Write the value to the field of `self` and add the old and new value of the field to the changes.
+/// The values are formatted with [Debug], or with the function given with the `audit` option.
+fn write_field_audited(pair: &FieldPair, value: Expr) -> Stmt {
+    let l_field_ident = &pair.l_field.path;
+    let l_field_name = get_path_name(&pair.l_field);
+    let format: Expr = match &pair.l_options.audit {
+        Some(audit) => parse_quote! { #audit (&self. #l_field_ident ) },
+        None => parse_quote! { ::std::format!("{:?}", self. #l_field_ident ) },
+    };
+    let write = write_field(pair, value);
+    parse_quote! {
+        {
+            let old = #format ;
+            #write
+            changes.push(::corresponding::FieldChange {
+                field: #l_field_name ,
+                old,
+                new: #format ,
+            });
+        }
+    }
+}
+
+/// Write the value to the field of `self` and keep the old value of the field as the `previous` value.
+/// The old value of a field with a merge strategy is cloned before the value is merged.
+fn write_field_with_undo(pair: &FieldPair, value: Expr) -> Stmt {
+    let l_field_ident = &pair.l_field.path;
+    if pair.l_options.merge.is_some() {
+        let write = write_field(pair, value);
+        return parse_quote! {
+            {
+                *previous = Some(self. #l_field_ident .clone());
+                #write
+            }
+        };
+    }
+    parse_quote! {
+        {
+            let value = #value ;
+            *previous = Some(::std::mem::replace(&mut self. #l_field_ident , value));
+        }
+    }
+}
+
+/// Get the fields of a struct. A flattened field is followed by the fields of its struct,
+/// which must be a struct in the same module.
+fn get_flat_fields<'a>(
+    item_struct: &'a syn::ItemStruct,
+    structs: &'a [syn::ItemStruct],
+) -> syn::Result<Vec<FlatField<'a>>> {
+    let mut flat_fields = vec![];
+    for field in &item_struct.fields {
+        let ident = match &field.ident {
+            Some(ident) => ident,
+            None => continue,
+        };
+        let mut path = Punctuated::new();
+        path.push(ident.clone());
+        flat_fields.push(FlatField { field, path });
+
+        if get_field_options(field)?.flatten {
+            let flattened = get_type(&field.ty)
+                .filter(|ty| !ty.option)
+                .and_then(|ty| structs.iter().find(|s| s.ident == ty.ident))
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(&field.ty, "expected a struct in this module")
+                })?;
+            for mut flat_field in get_flat_fields(flattened, structs)? {
+                flat_field.path.insert(0, ident.clone());
+                flat_fields.push(flat_field);
+            }
+        }
+    }
+    Ok(flat_fields)
+}
+
+/// Get the path of a flat field as string, like `address.city`
+fn get_path_name(flat_field: &FlatField) -> String {
+    flat_field
+        .path
+        .iter()
+        .map(|ident| ident.unraw().to_string())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Get the name of a field as string
+fn get_field_name(field: &Field) -> String {
+    field
+        .ident
+        .as_ref()
+        .map(|ident| ident.unraw().to_string())
+        .unwrap_or_default()
+}
+
+/// Generate the `impl MoveCorresponding<Right> for Left` from two ItemStructs
+fn generate_move_corresponding_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the statements
+    let statements: Vec<Stmt> = pairs
+        .iter()
+        .map(|pair| generate_statement(pair, Access::Move, write_field))
+        .collect();
+
+    // Generate the impl
+    let rhs = generate_rhs_binding(pairs.iter().any(is_taken));
+    parse_quote! {
+        impl ::corresponding::MoveCorresponding< #r_ident > for #l_ident {
+            #[inline]
+            fn move_corresponding(&mut self, #rhs: #r_ident ) {
+                #(#statements)*
+            }
+        }
+    }
+}
+
+/// Generate the `impl CloneCorresponding<Right> for Left` from two ItemStructs
+fn generate_clone_corresponding_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the statements
+    let statements: Vec<Stmt> = pairs
+        .iter()
+        .map(|pair| generate_statement(pair, Access::Clone, write_field))
+        .collect();
+
+    // Generate the impl
+    parse_quote! {
+        impl ::corresponding::CloneCorresponding< #r_ident > for #l_ident {
+            #[inline]
+            fn clone_corresponding(&mut self, rhs: & #r_ident ) {
+                #(#statements)*
+            }
+        }
+    }
+}
+
+/// Generate the `impl MoveCorrespondingTracked<Right> for Left` from two ItemStructs
+fn generate_move_corresponding_tracked_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the statements
+    let statements: Vec<Stmt> = pairs
+        .iter()
+        .map(|pair| generate_statement(pair, Access::Move, write_field_tracked))
+        .collect();
+
+    // Generate the impl
+    let rhs = generate_rhs_binding(pairs.iter().any(is_taken));
+    parse_quote! {
+        impl ::corresponding::MoveCorrespondingTracked< #r_ident > for #l_ident {
+            #[inline]
+            fn move_corresponding_tracked(&mut self, #rhs: #r_ident ) -> ::std::vec::Vec<&'static str> {
+                let mut changes = ::std::vec::Vec::new();
+                #(#statements)*
+                changes
+            }
+        }
+    }
+}
+
+/// Generate the `impl MoveCorrespondingChanged<Right> for Left` from two ItemStructs
+fn generate_move_corresponding_changed_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the statements
+    let statements: Vec<Stmt> = pairs
+        .iter()
+        .map(|pair| generate_statement(pair, Access::Move, write_field_changed))
+        .collect();
+
+    // The target fields need to implement `PartialEq`, and `Clone` when they have a merge strategy
+    let where_clause = join_where_clauses(
+        generate_where_clause(
+            pairs.iter().map(|pair| &pair.l_field.field.ty),
+            parse_quote! { ::std::cmp::PartialEq },
+        ),
+        generate_where_clause(
+            get_merged_types(pairs),
+            parse_quote! { ::std::clone::Clone },
+        ),
+    );
+
+    // Generate the impl
+    let rhs = generate_rhs_binding(pairs.iter().any(is_taken));
+    parse_quote! {
+        impl ::corresponding::MoveCorrespondingChanged< #r_ident > for #l_ident #where_clause {
+            #[inline]
+            fn move_corresponding_changed(&mut self, #rhs: #r_ident ) -> ::std::vec::Vec<&'static str> {
+                let mut changes = ::std::vec::Vec::new();
+                #(#statements)*
+                changes
+            }
+        }
+    }
+}
+
+/// Generate the `impl MoveCorrespondingAudited<Right> for Left` from two ItemStructs
+fn generate_move_corresponding_audited_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the statements
+    let statements: Vec<Stmt> = pairs
+        .iter()
+        .map(|pair| generate_statement(pair, Access::Move, write_field_audited))
+        .collect();
+
+    // The target fields without an `audit` function need to implement `Debug`
+    let where_clause = generate_where_clause(
+        pairs
+            .iter()
+            .filter(|pair| pair.l_options.audit.is_none())
+            .map(|pair| &pair.l_field.field.ty),
+        parse_quote! { ::std::fmt::Debug },
+    );
+
+    // Generate the impl
+    let rhs = generate_rhs_binding(pairs.iter().any(is_taken));
+    parse_quote! {
+        impl ::corresponding::MoveCorrespondingAudited< #r_ident > for #l_ident #where_clause {
+            #[inline]
+            fn move_corresponding_audited(&mut self, #rhs: #r_ident ) -> ::std::vec::Vec<::corresponding::FieldChange> {
+                let mut changes = ::std::vec::Vec::new();
+                #(#statements)*
+                changes
+            }
+        }
+    }
+}
+
+/// Generate the `impl MoveCorrespondingWithUndo<Right> for Left` from two ItemStructs
+fn generate_move_corresponding_with_undo_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Vec<Item> {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+    let vis = &l.vis;
+
+    // The undo patch is a struct with the previous value of every corresponding field in an `Option`,
+    // named after the path of the field
+    let undo = format_ident!("{}UndoFrom{}", l_ident, r_ident);
+    let undo_name = undo.to_string();
+    let names: Vec<Ident> = pairs
+        .iter()
+        .map(|pair| match pair.l_field.path.len() {
+            1 => pair.l_field.path[0].clone(),
+            _ => format_ident!("{}", get_path_name(&pair.l_field).replace('.', "_")),
+        })
+        .collect();
+    let field_names: Vec<String> = names.iter().map(|name| name.unraw().to_string()).collect();
+    let field_vis: Vec<&Visibility> = pairs.iter().map(|pair| &pair.l_field.field.vis).collect();
+    let types: Vec<&Type> = pairs.iter().map(|pair| &pair.l_field.field.ty).collect();
+    let doc = format!(
+        "The previous values of the fields of `{l_ident}` written by `move_corresponding_with_undo` with `{r_ident}`"
+    );
+
+    // Generate the statements keeping the previous values
+    let statements: Vec<Stmt> = pairs
+        .iter()
+        .zip(&names)
+        .map(|(pair, name)| {
+            let statement = generate_statement(pair, Access::Move, write_field_with_undo);
+            parse_quote! {
+                {
+                    let previous = &mut undo. #name ;
+                    #statement
+                }
+            }
+        })
+        .collect();
+
+    // Generate the statements restoring the previous values, the last written field first
+    let restores: Vec<Stmt> = pairs
+        .iter()
+        .zip(&names)
+        .rev()
+        .map(|(pair, name)| {
+            let l_field_ident = &pair.l_field.path;
+            parse_quote! {
+                if let Some(previous) = rhs. #name {
+                    self. #l_field_ident = previous;
+                }
+            }
+        })
+        .collect();
+
+    // The patch implements the traits that all field types implement
+    let where_clause = |bounds| generate_where_clause(types.iter().copied(), bounds);
+    let debug_where_clause = where_clause(parse_quote! { ::std::fmt::Debug });
+    let clone_where_clause = where_clause(parse_quote! { ::std::clone::Clone });
+    let partial_eq_where_clause = where_clause(parse_quote! { ::std::cmp::PartialEq });
+    let eq_where_clause = where_clause(parse_quote! { ::std::cmp::Eq });
+
+    // The target fields with a merge strategy need to implement `Clone`
+    let undo_where_clause = generate_where_clause(
+        get_merged_types(pairs),
+        parse_quote! { ::std::clone::Clone },
+    );
+
+    let rhs = generate_rhs_binding(pairs.iter().any(is_taken));
+
+    // Generate the patch and the impls
+    vec![
+        parse_quote! {
+            #[doc = #doc]
+            #vis struct #undo {
+                #( #field_vis #names : ::std::option::Option< #types > , )*
+            }
+        },
+        parse_quote! {
+            impl ::std::fmt::Debug for #undo #debug_where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.debug_struct( #undo_name )
+                        #( .field( #field_names , &self. #names ) )*
+                        .finish()
+                }
+            }
+        },
+        parse_quote! {
+            impl ::std::clone::Clone for #undo #clone_where_clause {
+                fn clone(&self) -> Self {
+                    #undo {
+                        #( #names : self. #names .clone() , )*
+                    }
+                }
+            }
+        },
+        parse_quote! {
+            impl ::std::cmp::PartialEq for #undo #partial_eq_where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    true #( && self. #names == other. #names )*
+                }
+            }
+        },
+        parse_quote! {
+            impl ::std::cmp::Eq for #undo #eq_where_clause {}
+        },
+        parse_quote! {
+            impl ::corresponding::MoveCorrespondingWithUndo< #r_ident > for #l_ident #undo_where_clause {
+                type Undo = #undo ;
+
+                #[inline]
+                fn move_corresponding_with_undo(&mut self, #rhs: #r_ident ) -> Self::Undo {
+                    let mut undo = #undo {
+                        #( #names : None , )*
+                    };
+                    #(#statements)*
+                    undo
+                }
+            }
+        },
+        parse_quote! {
+            impl ::corresponding::MoveCorresponding< #undo > for #l_ident {
+                #[inline]
+                fn move_corresponding(&mut self, rhs: #undo ) {
+                    #(#restores)*
+                }
+            }
+        },
+    ]
+}
+
+/// Generate the `impl MoveCorrespondingChecked<Right> for Left` from two ItemStructs
+fn generate_move_corresponding_checked_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the statements
+    let statements: Vec<Stmt> = pairs
+        .iter()
+        .map(|pair| generate_statement(pair, Access::Move, write_field_checked))
+        .collect();
+
+    // The target fields need to implement `PartialEq`
+    let where_clause = generate_where_clause(
+        pairs.iter().map(|pair| &pair.l_field.field.ty),
+        parse_quote! { ::std::cmp::PartialEq },
+    );
+
+    // Generate the impl
+    let rhs = generate_rhs_binding(pairs.iter().any(is_taken));
+    parse_quote! {
+        impl ::corresponding::MoveCorrespondingChecked< #r_ident > for #l_ident #where_clause {
+            #[inline]
+            fn move_corresponding_checked_with(
+                &mut self,
+                #rhs: #r_ident ,
+                written: &mut ::std::vec::Vec<&'static str>,
+            ) -> ::std::result::Result<(), ::corresponding::Conflict> {
+                #(#statements)*
+                ::std::result::Result::Ok(())
+            }
+        }
+    }
+}
+
+/// Generate the `impl SplitCorresponding<(Left0, Left1, ...)> for Right` from the ItemStructs.
+/// A field of the right struct is moved to the last part reading it, and cloned to the parts before.
+fn generate_split_corresponding_impl(
+    r: &syn::ItemStruct,
+    parts: &[(&syn::ItemStruct, NewLhs)],
+    structs: &[syn::ItemStruct],
+) -> syn::Result<Item> {
+    let r_ident = &r.ident;
+    let l_idents: Vec<&Ident> = parts.iter().map(|(l, _)| &l.ident).collect();
+    let part_pairs = parts
+        .iter()
+        .map(|(l, _)| get_corresponding_fields(l, r, structs))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // Generate the value of every part
+    let mut values: Vec<Expr> = vec![];
+    let mut cloned_types: Vec<&Type> = vec![];
+    let mut taken = false;
+    for (i, pairs) in part_pairs.iter().enumerate() {
+        let later_pairs: Vec<&FieldPair> = part_pairs[i + 1..].iter().flatten().collect();
+        let statements: Vec<Stmt> = pairs
+            .iter()
+            .map(|pair| {
+                let cloned = later_pairs
+                    .iter()
+                    .any(|later_pair| reads_same_field(pair, later_pair));
+                let access = match &pair.source {
+                    Source::Field(r_field) if cloned => {
+                        cloned_types.push(&r_field.field.ty);
+                        Access::Clone
+                    }
+                    Source::Nested(_) if cloned => {
+                        cloned_types.push(&pair.r_type.ty);
+                        Access::Clone
+                    }
+                    _ => {
+                        taken |= is_taken(pair);
+                        Access::Move
+                    }
+                };
+                generate_statement(pair, access, write_lhs_field)
+            })
+            .collect();
+
+        let value = &parts[i].1.value;
+        values.push(parse_quote! {
+            {
+                let mut lhs = #value ;
+                #(#statements)*
+                lhs
+            }
+        });
+    }
+
+    // The parts need to be constructed, and the cloned fields need to implement `Clone`
+    let mut predicates: Vec<WherePredicate> = parts
+        .iter()
+        .flat_map(|(_, new_lhs)| &new_lhs.where_clause)
+        .flat_map(|where_clause| where_clause.predicates.clone())
+        .collect();
+    if let Some(where_clause) = generate_where_clause(
+        cloned_types.into_iter(),
+        parse_quote! { ::std::clone::Clone },
+    ) {
+        predicates.extend(where_clause.predicates);
+    }
+
+    // Generate the impl
+    let rhs = generate_rhs_binding(taken);
+    Ok(parse_quote! {
+        impl ::corresponding::SplitCorresponding<( #(#l_idents),* )> for #r_ident where #(#predicates),* {
+            #[inline]
+            #[allow(clippy::field_reassign_with_default)]
+            fn split_corresponding(self) -> ( #(#l_idents),* ) {
+                let #rhs = self;
+                ( #(#values),* )
+            }
+        }
+    })
+}
+
+/// Generate the binding of `rhs`, which is mutable when a field is taken out of an `Option` of it
+fn generate_rhs_binding(taken: bool) -> Pat {
+    if taken {
+        parse_quote! { mut rhs }
+    } else {
+        parse_quote! { rhs }
+    }
+}
+
+/// Check whether a field is taken out of an `Option` of the right struct when moving
+fn is_taken(pair: &FieldPair) -> bool {
+    matches!(&pair.source, Source::Nested(NestedField { take: true, .. }))
+}
+
+/// Check whether two pairs read the same field of the right struct, or a field inside of it.
+/// A computed field reads all fields.
+fn reads_same_field(pair: &FieldPair, other: &FieldPair) -> bool {
+    let root = |source: &Source| match source {
+        Source::Field(r_field) => r_field.path.first().cloned(),
+        Source::Nested(nested) => nested.prefix.first().cloned(),
+        Source::Compute(_) => None,
+    };
+    match (root(&pair.source), root(&other.source)) {
+        (Some(ident), Some(other_ident)) => ident == other_ident,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+/// Generate the `impl ReplaceCorresponding<Right> for Left` from two ItemStructs
+fn generate_replace_corresponding_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+    structs: &[syn::ItemStruct],
+) -> syn::Result<Item> {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the statements resetting the fields without a corresponding field.
+    // The fields of a flattened field are reset one by one.
+    let mut statements: Vec<Stmt> = vec![];
+    let mut types: Vec<&Type> = vec![];
+    for l_field in get_flat_fields(l, structs)? {
+        let l_options = get_field_options(l_field.field)?;
+        if l_options.skip
+            || l_options.flatten
+            || pairs.iter().any(|pair| {
+                pair.l_field.path == l_field.path || is_inside(&l_field.path, &pair.l_field.path)
+            })
+        {
+            continue;
+        }
+
+        let l_field_ident = &l_field.path;
+        let value: Expr = match l_options.default {
+            Some(default) => default,
+            None => {
+                types.push(&l_field.field.ty);
+                parse_quote! { ::std::default::Default::default() }
+            }
+        };
+        statements.push(parse_quote! { self. #l_field_ident = #value ; });
+    }
+
+    // Generate the statements moving the corresponding fields
+    statements.extend(
+        pairs
+            .iter()
+            .map(|pair| generate_statement(pair, Access::Move, write_field)),
+    );
+
+    // The reset fields without a `default` option need to implement `Default`
+    let where_clause =
+        generate_where_clause(types.into_iter(), parse_quote! { ::std::default::Default });
+
+    // Generate the impl
+    let rhs = generate_rhs_binding(pairs.iter().any(is_taken));
+    Ok(parse_quote! {
+        impl ::corresponding::ReplaceCorresponding< #r_ident > for #l_ident #where_clause {
+            #[inline]
+            fn replace_corresponding(&mut self, #rhs: #r_ident ) {
+                #(#statements)*
+            }
+        }
+    })
+}
+
+/// Generate the `impl TryMoveCorresponding<Right> for Left` from two ItemStructs
+fn generate_try_move_corresponding_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Return the error of the first source field of type `Result<T, E>` that is an `Err`,
+    // before any field is moved
+    let mut errors: Vec<&Type> = vec![];
+    let mut statements: Vec<Stmt> = vec![];
+    for pair in pairs {
+        if let Source::Nested(NestedField {
+            prefix,
+            name,
+            result: Some(error),
+            ..
+        }) = &pair.source
+        {
+            errors.push(error);
+            statements.push(parse_quote! {
+                if let ::std::result::Result::Err(error) = rhs. #prefix {
+                    return ::std::result::Result::Err(::corresponding::FieldError {
+                        field: #name ,
+                        error: error.into(),
+                    });
+                }
+            });
+        }
+    }
+    statements.extend(
+        pairs
+            .iter()
+            .map(|pair| generate_statement(pair, Access::Move, write_field)),
+    );
+
+    // The errors need to convert into a boxed `Error`
+    let where_clause = generate_where_clause(
+        errors.into_iter(),
+        parse_quote! { ::std::convert::Into<::std::boxed::Box<dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync>> },
+    );
+
+    // Generate the impl
+    let rhs = generate_rhs_binding(pairs.iter().any(is_taken));
+    parse_quote! {
+        impl ::corresponding::TryMoveCorresponding< #r_ident > for #l_ident #where_clause {
+            #[inline]
+            fn try_move_corresponding(&mut self, #rhs: #r_ident ) -> ::std::result::Result<(), ::corresponding::FieldError> {
+                #(#statements)*
+                ::std::result::Result::Ok(())
+            }
+        }
+    }
+}
+
+/// Generate the `impl FillCorresponding<Right> for Left` from two ItemStructs
+fn generate_fill_corresponding_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the statements. From the same struct, only the fields with the same name are filled.
+    let pairs: Vec<&FieldPair> = pairs
+        .iter()
+        .filter(|pair| match &pair.source {
+            Source::Field(r_field) => l != r || r_field.path == pair.l_field.path,
+            _ => l != r,
+        })
+        .collect();
+    let statements: Vec<Stmt> = pairs
+        .iter()
+        .map(|pair| generate_statement(pair, Access::Move, write_field_filled))
+        .collect();
+
+    // The target fields that are not an `Option` are compared to their default value
+    let where_clause = generate_where_clause(
+        pairs
+            .iter()
+            .filter(|pair| !pair.l_type.option)
+            .map(|pair| &pair.l_field.field.ty),
+        parse_quote! { ::std::cmp::PartialEq + ::std::default::Default },
+    );
+
+    // Generate the impl
+    let rhs = generate_rhs_binding(pairs.iter().copied().any(is_taken));
+    parse_quote! {
+        impl ::corresponding::FillCorresponding< #r_ident > for #l_ident #where_clause {
+            #[inline]
+            fn fill_corresponding(&mut self, #rhs: #r_ident ) {
+                #(#statements)*
+            }
+        }
+    }
+}
+
+/// Generate the `impl CorrespondingFields<Right> for Left` from two ItemStructs
+fn generate_corresponding_fields_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the field mappings
+    let mappings: Vec<Expr> = pairs
+        .iter()
+        .filter_map(|pair| match &pair.source {
+            Source::Field(r_field) => Some((pair, get_path_name(r_field))),
+            Source::Nested(nested) => Some((pair, nested.name.clone())),
+            Source::Compute(_) => None,
+        })
+        .map(|(pair, source)| {
+            let target = get_path_name(&pair.l_field);
+            let source_option = pair.r_type.option;
+            let target_option = pair.l_type.option;
+            parse_quote! {
+                ::corresponding::FieldMapping {
+                    source: #source ,
+                    target: #target ,
+                    source_option: #source_option ,
+                    target_option: #target_option ,
+                }
+            }
+        })
+        .collect();
+
+    // Generate the impl
+    parse_quote! {
+        impl ::corresponding::CorrespondingFields< #r_ident > for #l_ident {
+            const FIELDS: &'static [::corresponding::FieldMapping] = &[ #(#mappings),* ];
+        }
+    }
+}
+
+/// Generate the `impl FieldNames for Left` from an ItemStruct
+fn generate_field_names_impl(
+    l: &syn::ItemStruct,
+    structs: &[syn::ItemStruct],
+) -> syn::Result<Item> {
+    let l_ident = &l.ident;
+    let names: Vec<String> = get_flat_fields(l, structs)?
+        .iter()
+        .map(get_path_name)
+        .collect();
+    Ok(parse_quote! {
+        impl ::corresponding::FieldNames for #l_ident {
+            const NAMES: &'static [&'static str] = &[ #(#names),* ];
+        }
+    })
+}
+
+/// Generate the `impl TakeCorresponding<Right> for Left` from two ItemStructs
+fn generate_take_corresponding_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the statements
+    let statements: Vec<Stmt> = pairs
+        .iter()
+        .map(|pair| generate_statement(pair, Access::Take, write_field))
+        .collect();
+
+    // Only the source fields that are not an `Option` need to implement `Default`
+    let where_clause = generate_where_clause(
+        pairs.iter().filter_map(|pair| match &pair.source {
+            Source::Field(r_field) if !pair.r_type.option => Some(&r_field.field.ty),
+            Source::Nested(nested) if !nested.option => Some(&pair.r_type.ty),
+            _ => None,
+        }),
+        parse_quote! { ::std::default::Default },
+    );
+
+    // Generate the impl
+    parse_quote! {
+        impl ::corresponding::TakeCorresponding< #r_ident > for #l_ident #where_clause {
+            #[inline]
+            fn take_corresponding(&mut self, rhs: &mut #r_ident ) {
+                #(#statements)*
+            }
+        }
+    }
+}
+
+/// Generate the `impl SwapCorresponding<Right> for Left` from two ItemStructs
+fn generate_swap_corresponding_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the statements, only for fields that are both `T` or both `Option<T>`
+    let statements: Vec<Stmt> = pairs
+        .iter()
+        .filter_map(|pair| match &pair.source {
+            Source::Field(r_field) if pair.l_type.option == pair.r_type.option => {
+                let l_field_ident = &pair.l_field.path;
+                let r_field_ident = &r_field.path;
+                Some(parse_quote! { ::std::mem::swap(&mut self. #l_field_ident , &mut rhs. #r_field_ident ); })
+            }
+            _ => None,
+        })
+        .collect();
+
+    // Generate the impl
+    parse_quote! {
+        impl ::corresponding::SwapCorresponding< #r_ident > for #l_ident {
+            #[inline]
+            fn swap_corresponding(&mut self, rhs: &mut #r_ident ) {
+                #(#statements)*
+            }
+        }
+    }
+}
+
+/// Generate the `impl DiffCorresponding<Right, Patch> for Left` from three ItemStructs
+fn generate_diff_corresponding_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    p: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+    let p_ident = &p.ident;
+
+    // Generate the statements for the fields of the patch that are in both structs
+    let mut statements: Vec<Stmt> = vec![];
+    let mut types: Vec<&Type> = vec![];
+    for pair in pairs {
+        let r_field = match &pair.source {
+            Source::Field(r_field) => r_field,
+            Source::Nested(_) | Source::Compute(_) => continue,
+        };
+        for p_field in &p.fields {
+            if let Some(p_type) = get_type(&p_field.ty) {
+                if p_field.ident == pair.l_field.field.ident && p_type.ident == pair.l_type.ident {
+                    let p_field_ident = &p_field.ident;
+                    let l_field_ident = &pair.l_field.path;
+                    let r_field_ident = &r_field.path;
+
+                    let statement: Stmt = match (
+                        p_type.option,
+                        pair.l_type.option,
+                        pair.r_type.option,
+                    ) {
+                        // Fields of the patch that are not an `Option` cannot tell whether a
+                        // value has changed, so they are always set, like the key of a struct
+                        (false, _, false) => {
+                            parse_quote! { patch. #p_field_ident = rhs. #r_field_ident .clone() ; }
+                        }
+                        (false, _, true) => continue,
+                        (true, false, false) => {
+                            parse_quote! { if self. #l_field_ident != rhs. #r_field_ident { patch. #p_field_ident = Some ( rhs. #r_field_ident .clone() ) } }
+                        }
+                        (true, true, false) => {
+                            parse_quote! { if self. #l_field_ident .as_ref() != Some ( &rhs. #r_field_ident ) { patch. #p_field_ident = Some ( rhs. #r_field_ident .clone() ) } }
+                        }
+                        (true, false, true) => {
+                            parse_quote! { if let Some ( r ) = &rhs. #r_field_ident { if self. #l_field_ident != *r { patch. #p_field_ident = Some ( r.clone() ) } } }
+                        }
+                        (true, true, true) => {
+                            parse_quote! { if rhs. #r_field_ident .is_some() && self. #l_field_ident != rhs. #r_field_ident { patch. #p_field_ident = rhs. #r_field_ident .clone() } }
+                        }
+                    };
+
+                    // A value that moving would skip is not a change
+                    statements.push(match generate_skip_condition(pair) {
+                        Some(condition) => parse_quote! { if !( #condition ) { #statement } },
+                        None => statement,
+                    });
+                    types.push(&pair.l_type.ty);
+                    types.push(&pair.r_type.ty);
+                }
+            }
+        }
+    }
+
+    // The compared fields need to implement `PartialEq` and `Clone`
+    let where_clause = generate_where_clause(
+        types.into_iter(),
+        parse_quote! { ::std::cmp::PartialEq + ::std::clone::Clone },
+    );
+
+    // Generate the impl
+    parse_quote! {
+        impl ::corresponding::DiffCorresponding< #r_ident , #p_ident > for #l_ident #where_clause {
+            #[inline]
+            fn diff_corresponding(&self, rhs: & #r_ident ) -> #p_ident {
+                let mut patch = < #p_ident as ::std::default::Default >::default();
+                #(#statements)*
+                patch
+            }
+        }
+    }
+}
+
+/// Generate the `impl EqCorresponding<Right> for Left` from two ItemStructs
+fn generate_eq_corresponding_impl(
+    l: &syn::ItemStruct,
+    r: &syn::ItemStruct,
+    pairs: &[FieldPair],
+) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+
+    // Generate the comparisons. A field is equal when moving it would not change `self`.
+    let mut comparisons: Vec<Expr> = pairs
+        .iter()
+        .map(|pair| {
+            // A value that moving would skip is equal
+            let comparison = generate_comparison(pair);
+            match generate_skip_condition(pair) {
+                Some(condition) => parse_quote! { ( #condition ) || ( #comparison ) },
+                None => comparison,
+            }
+        })
+        .collect();
+    if comparisons.is_empty() {
+        comparisons.push(parse_quote! { true });
+    }
+
+    // The compared fields need to implement `PartialEq`
+    let where_clause = generate_where_clause(
+        pairs
+            .iter()
+            .flat_map(|pair| [&pair.l_type.ty, &pair.r_type.ty]),
+        parse_quote! { ::std::cmp::PartialEq },
+    );
+
+    // Generate the impl
+    parse_quote! {
+        impl ::corresponding::EqCorresponding< #r_ident > for #l_ident #where_clause {
+            #[inline]
+            fn eq_corresponding(&self, rhs: & #r_ident ) -> bool {
+                #((#comparisons))&&*
+            }
+        }
+    }
+}
+
+/// Generate the comparison of a field of `self` with its source in `rhs`.
+/// A field is equal when moving it would not change `self`, also when the computed value is skipped.
+fn generate_comparison(pair: &FieldPair) -> Expr {
+    let l_field_ident = &pair.l_field.path;
+    let l_type = &pair.l_type.ty;
+    let r_field_ident = match &pair.source {
+        Source::Field(r_field) => &r_field.path,
+        Source::Nested(nested) => {
+            let value = generate_nested_value(nested, None);
+            return match (pair.l_type.option, pair.policy) {
+                (false, OptionPolicy::Default) => {
+                    parse_quote! { match #value { Some ( r ) => self. #l_field_ident == *r, None => self. #l_field_ident == < #l_type as ::std::default::Default >::default() } }
+                }
+                (false, _) => {
+                    parse_quote! { match #value { Some ( r ) => self. #l_field_ident == *r, None => true } }
+                }
+                (true, OptionPolicy::Some) => {
+                    parse_quote! { match #value { Some ( r ) => self. #l_field_ident .as_ref() == Some ( r ), None => true } }
+                }
+                (true, _) => parse_quote! { self. #l_field_ident .as_ref() == #value },
+            };
+        }
+        Source::Compute(compute) => {
+            let skip_if = match &pair.skip_if {
+                Some(skip_if) => skip_if,
+                None => return parse_quote! { self. #l_field_ident == #compute (rhs) },
+            };
+            let condition: Expr = if pair.l_type.option {
+                parse_quote! { computed.as_ref().is_some_and(|r| #skip_if (r)) }
+            } else {
+                parse_quote! { #skip_if (&computed) }
+            };
+            return parse_quote! {
+                {
+                    let computed = #compute (rhs);
+                    #condition || self. #l_field_ident == computed
+                }
+            };
+        }
+    };
+
+    match (pair.l_type.option, pair.r_type.option, pair.policy) {
+        (false, false, _) => parse_quote! { self. #l_field_ident == rhs. #r_field_ident },
+        (true, false, _) => {
+            parse_quote! { self. #l_field_ident .as_ref() == Some ( &rhs. #r_field_ident ) }
+        }
+        (false, true, OptionPolicy::Default) => {
+            parse_quote! { match &rhs. #r_field_ident { Some ( r ) => self. #l_field_ident == *r, None => self. #l_field_ident == < #l_type as ::std::default::Default >::default() } }
+        }
+        (false, true, _) => {
+            parse_quote! { match &rhs. #r_field_ident { Some ( r ) => self. #l_field_ident == *r, None => true } }
+        }
+        (true, true, OptionPolicy::Some) => {
+            parse_quote! { rhs. #r_field_ident .is_none() || self. #l_field_ident == rhs. #r_field_ident }
+        }
+        (true, true, _) => parse_quote! { self. #l_field_ident == rhs. #r_field_ident },
+    }
+}
+
+/// Generate a where clause requiring the given traits for all given types.
+/// The bounds are higher-ranked, so a type not implementing the traits doesn't
+/// fail the compilation, but only makes the implementation unavailable.
+fn generate_where_clause<'a>(
+    types: impl Iterator<Item = &'a Type>,
+    bounds: Punctuated<TypeParamBound, Token![+]>,
+) -> Option<WhereClause> {
+    let mut types: Vec<&Type> = types.collect();
+    types.sort_by_key(|ty| ty.to_token_stream().to_string());
+    types.dedup_by_key(|ty| ty.to_token_stream().to_string());
+    if types.is_empty() {
+        None
+    } else {
+        Some(parse_quote! { where #(for<'a> #types: #bounds),* })
+    }
+}
+
+/// Join the predicates of two where clauses
+fn join_where_clauses(
+    where_clause: Option<WhereClause>,
+    other: Option<WhereClause>,
+) -> Option<WhereClause> {
+    match (where_clause, other) {
+        (Some(mut where_clause), Some(other)) => {
+            where_clause.predicates.extend(other.predicates);
+            Some(where_clause)
+        }
+        (where_clause, other) => where_clause.or(other),
+    }
+}
+
+/// Get the types of the target fields with a merge strategy, which are cloned to compare
+/// or keep their previous values
+fn get_merged_types<'b>(pairs: &'b [FieldPair]) -> impl Iterator<Item = &'b Type> {
+    pairs
+        .iter()
+        .filter(|pair| pair.l_options.merge.is_some())
+        .map(|pair| &pair.l_field.field.ty)
+}
+
+/// Get the type `T` and the error type `E` of a type `Result<T, E>`
+fn get_result_type(ty: &syn::Type) -> Option<(OptionType, Type)> {
+    if let Type::Path(TypePath {
+        path: Path { segments, .. },
+        ..
+    }) = ty
+    {
+        if let Some(segment) = segments.first() {
+            if segment.ident.to_string().as_str() == "Result" {
+                if let PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                    args, ..
+                }) = &segment.arguments
+                {
+                    if let (Some(GenericArgument::Type(ty)), Some(GenericArgument::Type(error))) =
+                        (args.first(), args.iter().nth(1))
+                    {
+                        if let Some(OptionType {
+                            ident,
+                            option: false,
+                            ..
+                        }) = get_type(ty)
+                        {
+                            let r_type = OptionType {
+                                ident,
+                                option: true,
+                                ty: ty.clone(),
+                            };
+                            return Some((r_type, error.clone()));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Check whether the given struct has `#[derive(...)]` attribute
+fn has_derive(l: &syn::ItemStruct, derive: &str) -> bool {
+    for attribute in l.clone().attrs {
+        if let Some(PathSegment { ident, .. }) = attribute.path.segments.first() {
+            if ident.to_string().as_str() == "derive" {
+                for token_tree in attribute.tokens {
+                    if let TokenTree::Group(group) = token_tree {
+                        for token_tree2 in group.stream() {
+                            if let TokenTree::Ident(ident) = token_tree2 {
+                                if ident.to_string().as_str() == derive {
+                                    return true;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Generate the value of a new left struct.
+/// When the struct derives Default, use the Default trait, except for the fields with a `default` option.
+/// Else, when a field has a `default` option, construct the struct with the default values of the fields.
+/// The fields without a `default` option must implement Default then.
+/// Otherwise, the struct cannot be constructed.
+fn generate_new_lhs(l: &syn::ItemStruct) -> syn::Result<Option<NewLhs>> {
+    let l_ident = &l.ident;
+    let mut field_idents = vec![];
+    let mut values: Vec<Expr> = vec![];
+    let mut types = vec![];
+    for field in &l.fields {
+        if let Some(default) = get_field_options(field)?.default {
+            field_idents.push(&field.ident);
+            values.push(default);
+        } else {
+            types.push(&field.ty);
+        }
+    }
+
+    if has_derive(l, "Default") {
+        let value = if values.is_empty() {
+            parse_quote! { #l_ident ::default() }
+        } else if types.is_empty() {
+            parse_quote! { #l_ident { #( #field_idents : #values ),* } }
+        } else {
+            parse_quote! { #l_ident { #( #field_idents : #values , )* .. #l_ident ::default() } }
+        };
+        Ok(Some(NewLhs {
+            value,
+            where_clause: None,
+        }))
+    } else if !values.is_empty() {
+        for field in &l.fields {
+            if !field_idents.contains(&&field.ident) {
+                field_idents.push(&field.ident);
+                values.push(parse_quote! { ::std::default::Default::default() });
+            }
+        }
+        Ok(Some(NewLhs {
+            value: parse_quote! { #l_ident { #( #field_idents : #values ),* } },
+            where_clause: generate_where_clause(
+                types.into_iter(),
+                parse_quote! { ::std::default::Default },
+            ),
+        }))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Generate `impl From<Right> for Left`
+/// Just construct a new object and move the corresponding fields
+fn generate_from_impl(l: &syn::ItemStruct, r: &syn::ItemStruct, new_lhs: &NewLhs) -> Item {
+    let l_ident = &l.ident;
+    let r_ident = &r.ident;
+    let NewLhs {
+        value,
+        where_clause,
+    } = new_lhs;
+
+    parse_quote! {
+        impl ::std::convert::From< #r_ident > for #l_ident #where_clause {
+            #[inline]
+            fn from(rhs: #r_ident ) -> Self {
+                use ::corresponding::MoveCorresponding;
+                let mut lhs = #value ;
+                lhs.move_corresponding(rhs);
+                lhs
+            }
+        }
+    }
+}
+
+/// Generate `impl<R0, R1, ...> From<(R0, R1, ...)> for Left` for tuples of two up to four structs.
+/// Just construct a new object and move the corresponding fields of all structs in the tuple
+fn generate_from_tuple_impls(l: &syn::ItemStruct, new_lhs: &NewLhs) -> Vec<Item> {
+    let l_ident = &l.ident;
+    let NewLhs {
+        value,
+        where_clause,
+    } = new_lhs;
+
+    (2..=4usize)
+        .map(|len| {
+            let r_idents: Vec<Ident> = (0..len).map(|i| format_ident!("R{}", i)).collect();
+            let mut where_clause = where_clause.clone().unwrap_or_else(|| parse_quote! { where });
+            where_clause.predicates.push(
+                parse_quote! { Self: ::corresponding::MoveCorresponding<( #(#r_idents),* )> },
+            );
+
+            parse_quote! {
+                impl< #(#r_idents),* > ::std::convert::From<( #(#r_idents),* )> for #l_ident #where_clause {
+                    #[inline]
+                    fn from(rhs: ( #(#r_idents),* )) -> Self {
+                        use ::corresponding::MoveCorresponding;
+                        let mut lhs = #value ;
+                        lhs.move_corresponding(rhs);
+                        lhs
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+/// Generate `impl<R> TryFromCorresponding<R> for Left`
+/// Just construct a new object and move the corresponding fields, checking for conflicts
+fn generate_try_from_corresponding_impl(l: &syn::ItemStruct, new_lhs: &NewLhs) -> Item {
+    let l_ident = &l.ident;
+    let NewLhs {
+        value,
+        where_clause,
+    } = new_lhs;
+
+    let mut where_clause = where_clause
+        .clone()
+        .unwrap_or_else(|| parse_quote! { where });
+    where_clause
+        .predicates
+        .push(parse_quote! { Self: ::corresponding::MoveCorrespondingChecked<R> });
+
+    parse_quote! {
+        impl<R> ::corresponding::TryFromCorresponding<R> for #l_ident #where_clause {
+            #[inline]
+            fn try_from_corresponding(rhs: R) -> ::std::result::Result<Self, ::corresponding::Conflict> {
+                use ::corresponding::MoveCorrespondingChecked;
+                let mut lhs = #value ;
+                lhs.move_corresponding_checked(rhs)?;
+                ::std::result::Result::Ok(lhs)
             }
         }
     }
 }
 
 /// Generate `impl FromCloned<Right> for Left`
-/// Just construct a new object by using the Default trait
-/// and clone the corresponding fields
-fn generate_from_cloned_impl(l: &syn::ItemStruct, r: &syn::ItemStruct) -> Item {
+/// Just construct a new object and clone the corresponding fields
+fn generate_from_cloned_impl(l: &syn::ItemStruct, r: &syn::ItemStruct, new_lhs: &NewLhs) -> Item {
     let l_ident = &l.ident;
     let r_ident = &r.ident;
+    let NewLhs {
+        value,
+        where_clause,
+    } = new_lhs;
 
     parse_quote! {
-        impl ::corresponding::FromCloned< #r_ident > for #l_ident {
+        impl ::corresponding::FromCloned< #r_ident > for #l_ident #where_clause {
             #[inline]
             fn from_cloned(rhs: & #r_ident ) -> Self {
                 use ::corresponding::CloneCorresponding;
-                let mut lhs = Self::default();
+                let mut lhs = #value ;
                 lhs.clone_corresponding(rhs);
                 lhs
             }
@@ -323,15 +2811,18 @@ fn get_type(ty: &syn::Type) -> Option<OptionType> {
                     args, ..
                 }) = segment.arguments.clone()
                 {
-                    if let Some(GenericArgument::Type(Type::Path(TypePath {
-                        path: Path { segments, .. },
-                        ..
-                    }))) = args.first()
+                    if let Some(GenericArgument::Type(
+                        ty @ Type::Path(TypePath {
+                            path: Path { segments, .. },
+                            ..
+                        }),
+                    )) = args.first()
                     {
                         if let Some(segment) = segments.first() {
                             return Some(OptionType {
                                 ident: segment.ident.clone(),
                                 option: true,
+                                ty: ty.clone(),
                             });
                         }
                     }
@@ -340,6 +2831,7 @@ fn get_type(ty: &syn::Type) -> Option<OptionType> {
                 return Some(OptionType {
                     ident: segment.ident.clone(),
                     option: false,
+                    ty: ty.clone(),
                 });
             }
         }
@@ -62,8 +62,7 @@ fn main() {
     let user_key = UserKey { id: 1 };
 
     // Construct the complete entity by combining received key and inserted fields
-    let mut user: User = user_key.into();
-    user.move_corresponding(user_insert);
+    let mut user = User::from((user_key, user_insert));
 
     // Print the created user
     println!("{user:#?}");
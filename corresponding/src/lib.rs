@@ -108,6 +108,40 @@
 //!
 //! Struct `B` doesn't derive [Default], so you cannot transform `A` to `B`. [From] is not implemented for this case.
 //!
+//! # Multiple sources
+//!
+//! [MoveCorresponding] is also implemented for tuples of two up to four structs, and all structs that implement `From`
+//! also implement it for these tuples. The corresponding fields of the structs are moved from left to right, so when
+//! more structs have the same field, the last struct wins. For example: `let user = User::from((user_key, user_insert));`
+//!
+//! To detect conflicting values instead, use [MoveCorrespondingChecked] and [TryFromCorresponding] of a struct with the
+//! `checked` option. They return a [Conflict] when more structs write the same field with different values.
+//!
+//! The other way around, [SplitCorresponding] splits a struct into a tuple of two up to four structs that can be constructed,
+//! like `let (user_key, user_insert): (UserKey, UserInsert) = user.split_corresponding();`. It is only implemented for the
+//! tuples named on the struct, like `#[corresponding(split = (UserKey, UserInsert))]`. A field more parts need is cloned.
+//!
+//! # Optional traits
+//!
+//! [MoveCorresponding], [From], [CloneCorresponding] and [FromCloned] are implemented for every pair of structs in the module.
+//! Every other trait adds an implementation for every other struct in the module, so it is only implemented for the target
+//! structs with the option named after it, like `#[corresponding(tracked, eq)]`:
+//!
+//! - `fields`: [CorrespondingFields], which is also used by [assert_corresponds]
+//! - `tracked`: [MoveCorrespondingTracked], which is also used by [Layered]
+//! - `changed`: [MoveCorrespondingChanged]
+//! - `audited`: [MoveCorrespondingAudited]
+//! - `undo`: [MoveCorrespondingWithUndo]
+//! - `checked`: [MoveCorrespondingChecked] and [TryFromCorresponding]
+//! - `take`: [TakeCorresponding]
+//! - `swap`: [SwapCorresponding]
+//! - `eq`: [EqCorresponding]
+//! - `fill`: [FillCorresponding]
+//! - `replace`: [ReplaceCorresponding]
+//! - `try_move`: [TryMoveCorresponding]
+//!
+//! [DiffCorresponding] is implemented for the structs with the `patch` option, see its documentation.
+//!
 //! # Options
 //!
 //! Also fields with types `T` and `Option<T>` are considered corresponding.
@@ -116,9 +150,293 @@
 //! - Moving `T` to `Option<T>` will always set the target field with `Some(value)`
 //! - Moving `Option<T>` to `Option<T>` will only set the target field when the source field is `Some(value)`
 //!
-//! This means there is no way of setting an [Option] to [None] by using [move_corresponding](MoveCorresponding::move_corresponding).
+//! This means there is no way of setting an [Option] to [None] by using [move_corresponding](MoveCorresponding::move_corresponding),
+//! unless another option policy is chosen.
 //!
 //! Deeper nested [Option]s are not supported, so `Option<Option<V>>` is considered as `Option<T>` with `T` = `Option<V>`.
+//!
+//! ## Option policies
+//!
+//! The rules above can be changed per field with the `#[corresponding(option = "...")]` attribute:
+//!
+//! - `option = "some"`: the default, only set the target field when the source field is `Some(value)`
+//! - `option = "overwrite"`: also set an `Option<T>` target field to [None] when the source field is [None]
+//! - `option = "default"`: set the target field to its [Default] value when the source field is [None]
+//!
+//! The attribute can be put on the target field or on the source field. When both fields have a policy, the policy of the target field is used.
+//!
+//! ```
+//! # use corresponding::derive_corresponding;
+//! #[derive_corresponding]
+//! mod my_mod {
+//!     #[derive(Debug, Default)]
+//!     pub struct A {
+//!         #[corresponding(option = "overwrite")]
+//!         pub a: Option<u8>,
+//!         #[corresponding(option = "default")]
+//!         pub b: u8,
+//!     }
+//!
+//!     pub struct B {
+//!         pub a: Option<u8>,
+//!         pub b: Option<u8>,
+//!     }
+//! }
+//! # use corresponding::MoveCorresponding;
+//! # use my_mod::*;
+//!
+//! let mut a = A { a: Some(1), b: 1 };
+//! a.move_corresponding(B { a: None, b: None });
+//! println!("{a:?}");      // Output: A { a: None, b: 0 }
+//! ```
+//!
+//! ## Results
+//!
+//! A source field of type `Result<T, E>` corresponds to a target field of type `T` or `Option<T>`, like a source field
+//! of type `Option<T>`. An `Err` doesn't set a target field of type `T`, and sets a target field of type `Option<T>` to
+//! `None`, unless another option policy is chosen. To get the error instead, use `try_move_corresponding` of
+//! `TryMoveCorresponding`, which returns a `FieldError` for the first source field that is an `Err`.
+//!
+//! # Skipping fields
+//!
+//! Put `#[corresponding(skip)]` on a field to never move it, even when the other struct has a field with the
+//! same name and type.
+//!
+//! Put `#[corresponding(skip_if = path::to::function)]` on a field to not move a value for which the function returns
+//! `true`, like `skip_serializing_if` of serde. For example, `#[corresponding(skip_if = String::is_empty)]` treats an
+//! empty string of a form as absent. The function is called with a reference to the value of the source field, or to the
+//! value inside it when it is an `Option`. The attribute can be put on the target field or on the source field. When both
+//! fields have a predicate, the predicate of the target field is used. A skipped value is also equal for
+//! `eq_corresponding`, and not a difference for `diff_corresponding`.
+//!
+//! # Default values
+//!
+//! When a struct derives `Default`, `From` starts with the default value of the struct before the corresponding fields
+//! are moved. Put `#[corresponding(default = expression)]` on a field to start with the value of the expression instead,
+//! for example `#[corresponding(default = Status::New)]`. The field keeps this value when the other struct has no
+//! corresponding field.
+//!
+//! A struct that doesn't derive `Default`, but has fields with a default value, also implements `From`. The other fields
+//! then start with the default value of their type, so their types must implement `Default`.
+//!
+//! # Merging fields
+//!
+//! Put `#[corresponding(merge = "...")]` on a target field to combine the value of the source field with the current
+//! value of the target field, instead of overwriting it:
+//!
+//! - `merge = "append"`: move the elements to the target field with `append`, for example for a `Vec`
+//! - `merge = "extend"`: add the elements to the target field with `extend`, for example for a `HashMap`
+//! - `merge = "sum"`: add the value to the target field with `+=`
+//! - `merge = "max"`: keep the largest of both values
+//! - `merge = path::to::function`: call a function like `fn(&mut T, T)` with the target field and the value
+//!
+//! The strategy is used when fields are moved, cloned or taken, except by `fill_corresponding`, which only fills unset
+//! fields. `move_corresponding_changed` reports the field when the merged value differs from the previous value, and
+//! `move_corresponding_with_undo` keeps the previous value, so the field needs to implement `Clone` for both.
+//! `move_corresponding_checked` and `try_from_corresponding` combine the values of more structs instead of returning
+//! a `Conflict`.
+//!
+//! The target field can't be of type `Option<T>`. A source field of type `Option<T>` is merged when it is `Some(value)`,
+//! or with the default value under `option = "default"`.
+//!
+//! ```compile_fail
+//! # use corresponding::derive_corresponding;
+//! #[derive_corresponding]
+//! mod my_mod {
+//!     pub struct A {
+//!         #[corresponding(merge = "sum")]      // Error: option `merge` is not supported on a field of type `Option<T>`
+//!         pub a: Option<u8>,
+//!     }
+//!
+//!     pub struct B {
+//!         pub a: u8,
+//!     }
+//! }
+//! ```
+//!
+//! # Computed fields
+//!
+//! Put `#[corresponding(compute(Struct = path::to::function))]` on a field to compute its value from the whole source
+//! struct, for example a `full_name` from the fields `first` and `last` of a `UserRow`. The function is called with a
+//! reference to the source struct, before any field is moved, and returns the value of the field. Give a function for
+//! every source struct, like `compute(UserRow = super::full_name, UserForm = super::form_full_name)`. The field is not
+//! moved from the other structs. With `#[corresponding(compute = path::to::function)]` the function is called with
+//! every other struct of the module, so it must be generic when the module has more than two structs.
+//!
+//! ```
+//! # use corresponding::derive_corresponding;
+//! # use my_mod::*;
+//! fn full_name(row: &UserRow) -> String {
+//!     format!("{} {}", row.first, row.last)
+//! }
+//!
+//! #[derive_corresponding]
+//! mod my_mod {
+//!     #[derive(Default)]
+//!     pub struct User {
+//!         pub id: u8,
+//!         #[corresponding(compute(UserRow = super::full_name))]
+//!         pub full_name: String,
+//!     }
+//!
+//!     pub struct UserRow {
+//!         pub id: u8,
+//!         pub first: String,
+//!         pub last: String,
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! # Flattening
+//!
+//! Put `#[corresponding(flatten)]` on a field to let the fields of its struct correspond as if they were fields of the
+//! outer struct. The type of the field must be a struct in the same module. The field itself still corresponds to a
+//! field with the same name and type.
+//!
+//! ```
+//! # use corresponding::derive_corresponding;
+//! #[derive_corresponding]
+//! mod my_mod {
+//!     #[derive(Default)]
+//!     pub struct User {
+//!         pub id: u8,
+//!         #[corresponding(flatten)]
+//!         pub address: Address,      // Moves `street` and `city` to and from `UserRow`
+//!     }
+//!
+//!     #[derive(Default)]
+//!     pub struct Address {
+//!         pub street: String,
+//!         pub city: String,
+//!     }
+//!
+//!     #[derive(Default)]
+//!     pub struct UserRow {
+//!         pub id: u8,
+//!         pub street: String,
+//!         pub city: String,
+//!     }
+//! }
+//! ```
+//!
+//! # Source paths
+//!
+//! Put `#[corresponding(from = "path")]` on a field to move the field at the path in the other struct to it, instead of
+//! the field with the same name. The path can be another field name, like `"name"`, or a nested field, like
+//! `"address.city"`. The types on the path must be structs in the same module.
+//!
+//! Add `?` to a field of type `Option<T>` to continue the path inside of it, like `"profile?.avatar"`. The nested field
+//! is then moved as an `Option`, so it is only set when all fields on the path are `Some`, following the option policy
+//! of the field. When more fields are read through the same `Option`, all but the last one are taken out of it with
+//! `Option::take` or `std::mem::take`, so a field of another type than `Option<T>` needs to implement `Default` then.
+//! A field of the other struct can only be moved once, so two fields can't read the same field, or a field inside of
+//! it, except through the same `Option`:
+//!
+//! ```compile_fail
+//! # use corresponding::derive_corresponding;
+//! #[derive_corresponding]
+//! mod my_mod {
+//!     pub struct A {
+//!         pub name: String,
+//!         #[corresponding(from = "name")]     // Error: fields `display` and `name` both read field `name`
+//!         pub display: String,
+//!     }
+//!
+//!     pub struct B {
+//!         pub name: String,
+//!     }
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! # use corresponding::derive_corresponding;
+//! #[derive_corresponding]
+//! mod my_mod {
+//!     pub struct A {
+//!         pub address: Address,
+//!         #[corresponding(from = "address.city")]     // Error: fields `city` and `address` both read field `address`
+//!         pub city: String,
+//!     }
+//!
+//!     pub struct B {
+//!         pub address: Address,
+//!     }
+//!
+//!     pub struct Address {
+//!         pub city: String,
+//!     }
+//! }
+//! ```
+//!
+//! # Exhaustive structs
+//!
+//! Put `#[corresponding(exhaustive)]` on a struct to make sure that it is always fully populated when fields
+//! are moved to it. Every field of an exhaustive struct, except the skipped fields and the fields with a default
+//! value, must have a corresponding field in all other structs of the module. Otherwise the module doesn't compile.
+//! The structs of flattened fields and of the fields on source paths are not checked, because they are only parts of
+//! other structs. A flattened field is exhaustive when the field itself or all fields of its struct correspond.
+//!
+//! ```compile_fail
+//! # use corresponding::derive_corresponding;
+//! #[derive_corresponding]
+//! mod my_mod {
+//!     #[corresponding(exhaustive)]
+//!     pub struct A {
+//!         pub a: u8,
+//!         pub b: u8,      // Error: field `b` of exhaustive struct `A` has no corresponding field in `B`
+//!     }
+//!
+//!     pub struct B {
+//!         pub a: u8,
+//!     }
+//! }
+//! ```
+//!
+//! # Roundtrips
+//!
+//! Put `#[corresponding(roundtrip = B)]` on a struct `A` to make sure that moving `A` to `B` and back to `A`
+//! preserves all data. Every field of `A` must have a field in `B` with the same name and exactly the same type,
+//! and the fields may not be skipped. The fields may also not have an option that changes the moved value on either
+//! side: `skip_if`, `merge`, `compute`, `from`, or an `option` policy other than `some`. Otherwise the module doesn't
+//! compile. `B` must be a struct in the same module.
+//!
+//! ```compile_fail
+//! # use corresponding::derive_corresponding;
+//! #[derive_corresponding]
+//! mod my_mod {
+//!     #[corresponding(roundtrip = B)]
+//!     pub struct A {
+//!         pub a: u8,
+//!         pub b: Option<u8>,      // Error: field `b` of `A` doesn't roundtrip to `B`: the field has type `u8` in `B`
+//!     }
+//!
+//!     pub struct B {
+//!         pub a: u8,
+//!         pub b: u8,
+//!     }
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! # use corresponding::derive_corresponding;
+//! #[derive_corresponding]
+//! mod my_mod {
+//!     #[corresponding(roundtrip = B)]
+//!     pub struct A {
+//!         pub a: u8,
+//!         pub b: Option<u8>,      // Error: field `b` of `A` doesn't roundtrip to `B`: the field has the `option` option in `B`
+//!     }
+//!
+//!     pub struct B {
+//!         pub a: u8,
+//!         #[corresponding(option = "overwrite")]
+//!         pub b: Option<u8>,
+//!     }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
 
 pub use corresponding_macros::derive_corresponding;
 pub mod prelude;
@@ -193,6 +511,1023 @@ pub trait MoveCorresponding<R> {
     fn move_corresponding(&mut self, rhs: R);
 }
 
+/// Implement [MoveCorresponding] for a tuple of structs, by moving the corresponding fields
+/// of the structs from left to right. When more structs have the same field, the last one wins.
+macro_rules! impl_move_corresponding_tuple {
+    ($($r:ident $rhs:ident),+) => {
+        impl<T, $($r),+> MoveCorresponding<($($r,)+)> for T
+        where
+            $(T: MoveCorresponding<$r>),+
+        {
+            #[inline]
+            fn move_corresponding(&mut self, rhs: ($($r,)+)) {
+                let ($($rhs,)+) = rhs;
+                $(self.move_corresponding($rhs);)+
+            }
+        }
+    };
+}
+
+impl_move_corresponding_tuple!(R0 rhs0, R1 rhs1);
+impl_move_corresponding_tuple!(R0 rhs0, R1 rhs1, R2 rhs2);
+impl_move_corresponding_tuple!(R0 rhs0, R1 rhs1, R2 rhs2, R3 rhs3);
+
+/// Trait holding the [FIELDS](CorrespondingFields::FIELDS) constant.
+pub trait CorrespondingFields<R> {
+    /// The corresponding fields that are moved from `R` to `Self`.
+    ///
+    /// Use this at runtime to see what a conversion will touch, for example to build a list of columns.
+    ///
+    /// Implemented for the structs with the `#[corresponding(fields)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(fields)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::{CorrespondingFields, FieldMapping};
+    /// use my_mod::*;
+    ///
+    /// assert_eq!(
+    ///     <A as CorrespondingFields<B>>::FIELDS,
+    ///     &[
+    ///         FieldMapping { source: "a", target: "a", source_option: false, target_option: false },
+    ///         FieldMapping { source: "b", target: "b", source_option: true, target_option: false },
+    ///     ]
+    /// );
+    /// ```
+    const FIELDS: &'static [FieldMapping];
+}
+
+/// Trait holding the [NAMES](FieldNames::NAMES) constant.
+pub trait FieldNames {
+    /// The names of all fields of the struct. The fields of a flattened field follow the field itself,
+    /// with their path like `address.city`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::FieldNames;
+    /// use my_mod::*;
+    ///
+    /// assert_eq!(A::NAMES, &["a", "b", "c"]);
+    /// ```
+    const NAMES: &'static [&'static str];
+}
+
+/// A field of the source struct that is moved to a field of the target struct,
+/// see [CorrespondingFields].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldMapping {
+    /// The name of the source field
+    pub source: &'static str,
+    /// The name of the target field
+    pub target: &'static str,
+    /// Whether the source field is an `Option<T>`, so the `Option` rules apply
+    pub source_option: bool,
+    /// Whether the target field is an `Option<T>`, so the `Option` rules apply
+    pub target_option: bool,
+}
+
+/// Assert that the corresponding fields moved from a source struct to a target struct are exactly
+/// the given target fields, in any order, and that all other fields of the source struct are ignored.
+///
+/// Use this in a test to catch fields that are added to one struct, but not to the other, so they are
+/// silently not moved. The moved fields are taken from [CorrespondingFields] and the fields of the source
+/// struct from [FieldNames]. A source field that is not moved must be listed after `ignore`. The target
+/// struct needs the `#[corresponding(fields)]` option.
+///
+/// # Example
+///
+/// ```
+/// # use corresponding::derive_corresponding;
+/// # #[derive_corresponding]
+/// # mod my_mod {
+/// #     #[derive(Debug, Default)]
+/// #     #[corresponding(fields)]
+/// #     pub struct A {
+/// #         pub a: u8,
+/// #         pub b: u8,
+/// #         pub c: u8,
+/// #     }
+/// #
+/// #     #[derive(Debug, Clone)]
+/// #     pub struct B {
+/// #         pub a: u8,
+/// #         pub b: Option<u8>,
+/// #         pub d: u8,
+/// #     }
+/// # }
+/// use corresponding::assert_corresponds;
+/// use my_mod::*;
+///
+/// assert_corresponds!(B => A, [a, b], ignore [d]);
+/// ```
+#[macro_export]
+macro_rules! assert_corresponds {
+    ($source:ty => $target:ty, [$($field:ident),* $(,)?] $(, ignore [$($ignored:ident),* $(,)?])? $(,)?) => {{
+        let mappings = <$target as $crate::CorrespondingFields<$source>>::FIELDS;
+        let mut actual: ::std::vec::Vec<&str> = mappings.iter().map(|mapping| mapping.target).collect();
+        let mut expected: ::std::vec::Vec<&str> = ::std::vec![$(::std::stringify!($field)),*];
+        actual.sort_unstable();
+        expected.sort_unstable();
+        ::std::assert_eq!(
+            actual,
+            expected,
+            "the fields moved from `{}` to `{}` are not the given fields",
+            ::std::stringify!($source),
+            ::std::stringify!($target),
+        );
+
+        // A field is moved when a mapping reads it, a field inside of it, or the field it is inside of
+        let inside = |path: &str, outer: &str| {
+            path.strip_prefix(outer).is_some_and(|rest| rest.starts_with('.'))
+        };
+        let ignored: ::std::vec::Vec<&str> = ::std::vec![$($(::std::stringify!($ignored)),*)?];
+        let not_moved: ::std::vec::Vec<&str> = <$source as $crate::FieldNames>::NAMES
+            .iter()
+            .copied()
+            .filter(|name| {
+                !mappings.iter().any(|mapping| {
+                    let source = mapping.source.replace('?', "");
+                    source == *name || inside(&source, name) || inside(name, &source)
+                })
+            })
+            .filter(|name| !ignored.iter().any(|ignored| name == ignored || inside(name, ignored)))
+            .collect();
+        ::std::assert!(
+            not_moved.is_empty(),
+            "the fields {:?} of `{}` are not moved to `{}` and not ignored",
+            not_moved,
+            ::std::stringify!($source),
+            ::std::stringify!($target),
+        );
+    }};
+}
+
+/// Trait holding the [move_corresponding_tracked](MoveCorrespondingTracked::move_corresponding_tracked) function.
+pub trait MoveCorrespondingTracked<R> {
+    /// Move the corresponding fields from `rhs` to `self` and return the changeset: the names of the
+    /// fields of `self` that are written.
+    ///
+    /// Fields are moved like [move_corresponding](MoveCorresponding::move_corresponding), so a source field
+    /// of type `Option<T>` that is [None] is not written and is not in the changeset.
+    ///
+    /// Implemented for the structs with the `#[corresponding(tracked)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(tracked)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::MoveCorrespondingTracked;
+    /// use my_mod::*;
+    ///
+    /// let mut a = A { a: 1, b: 1, c: 1 };
+    ///
+    /// let changes = a.move_corresponding_tracked(B { a: 1, b: None, d: 2 });
+    /// assert_eq!(changes, vec!["a"]);
+    /// ```
+    fn move_corresponding_tracked(&mut self, rhs: R) -> Vec<&'static str>;
+}
+
+/// A value that is built from layers, like the layers of a configuration, recording for every
+/// field which layer has set it.
+///
+/// Each layer is moved to the value with [move_corresponding_tracked](MoveCorrespondingTracked::move_corresponding_tracked),
+/// so a later layer overrides the fields set by an earlier layer. A field that is not set by any layer keeps its
+/// initial value and has no source. The value can be read through [Deref](std::ops::Deref), but not changed
+/// directly, so the sources stay correct. The value needs the `#[corresponding(tracked)]` option.
+///
+/// # Example
+///
+/// ```
+/// # use corresponding::derive_corresponding;
+/// # #[derive_corresponding]
+/// # mod my_mod {
+/// #     #[derive(Debug, Default)]
+/// #     #[corresponding(tracked)]
+/// #     pub struct A {
+/// #         pub a: u8,
+/// #         pub b: u8,
+/// #         pub c: u8,
+/// #     }
+/// #
+/// #     #[derive(Debug, Clone)]
+/// #     pub struct B {
+/// #         pub a: u8,
+/// #         pub b: Option<u8>,
+/// #         pub d: u8,
+/// #     }
+/// # }
+/// use corresponding::Layered;
+/// use my_mod::*;
+///
+/// let a = Layered::new(A::default())
+///     .with_layer("file", B { a: 1, b: Some(1), d: 1 })
+///     .with_layer("env", B { a: 2, b: None, d: 2 });
+///
+/// assert_eq!((a.a, a.b, a.c), (2, 1, 0));
+/// assert_eq!(a.source("a"), Some("env"));
+/// assert_eq!(a.source("b"), Some("file"));
+/// assert_eq!(a.source("c"), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Layered<T> {
+    value: T,
+    layers: Vec<String>,
+    sources: BTreeMap<&'static str, usize>,
+}
+
+impl<T> Layered<T> {
+    /// Start with an initial value, without any layers
+    pub fn new(value: T) -> Self {
+        Layered {
+            value,
+            layers: vec![],
+            sources: BTreeMap::new(),
+        }
+    }
+
+    /// Move the corresponding fields of a layer to the value, and record the name of the layer
+    /// as the source of the written fields
+    pub fn layer<R>(&mut self, name: impl Into<String>, rhs: R)
+    where
+        T: MoveCorrespondingTracked<R>,
+    {
+        let changes = self.value.move_corresponding_tracked(rhs);
+        for field in changes {
+            self.sources.insert(field, self.layers.len());
+        }
+        self.layers.push(name.into());
+    }
+
+    /// Add a layer like [layer](Layered::layer), and return the layered value
+    pub fn with_layer<R>(mut self, name: impl Into<String>, rhs: R) -> Self
+    where
+        T: MoveCorrespondingTracked<R>,
+    {
+        self.layer(name, rhs);
+        self
+    }
+
+    /// Returns the name of the layer that has set the field last, or [None] when no layer has set it
+    pub fn source(&self, field: &str) -> Option<&str> {
+        self.sources
+            .get(field)
+            .map(|&layer| self.layers[layer].as_str())
+    }
+
+    /// Returns the names of all fields set by a layer, with the name of the layer that has set it last,
+    /// sorted by the name of the field
+    pub fn sources(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.sources
+            .iter()
+            .map(|(&field, &layer)| (field, self.layers[layer].as_str()))
+    }
+
+    /// Returns the names of the layers, in the order they are added
+    pub fn layers(&self) -> &[String] {
+        &self.layers
+    }
+
+    /// Returns the layered value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> std::ops::Deref for Layered<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Trait holding the [move_corresponding_changed](MoveCorrespondingChanged::move_corresponding_changed) function.
+pub trait MoveCorrespondingChanged<R> {
+    /// Move the corresponding fields from `rhs` to `self` and return the changeset: the names of the
+    /// fields of `self` whose value changed.
+    ///
+    /// Like [move_corresponding_tracked](MoveCorrespondingTracked::move_corresponding_tracked), but fields
+    /// are only written and added to the changeset when the new value differs from the current value.
+    /// The target fields need to implement [PartialEq].
+    ///
+    /// Implemented for the structs with the `#[corresponding(changed)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(changed)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::MoveCorrespondingChanged;
+    /// use my_mod::*;
+    ///
+    /// let mut a = A { a: 1, b: 1, c: 1 };
+    ///
+    /// let changes = a.move_corresponding_changed(B { a: 1, b: Some(2), d: 2 });
+    /// assert_eq!(changes, vec!["b"]);
+    /// ```
+    fn move_corresponding_changed(&mut self, rhs: R) -> Vec<&'static str>;
+}
+
+/// Trait holding the [move_corresponding_audited](MoveCorrespondingAudited::move_corresponding_audited) function.
+pub trait MoveCorrespondingAudited<R> {
+    /// Move the corresponding fields from `rhs` to `self` and return the old and new value of every
+    /// field of `self` that is written.
+    ///
+    /// Fields are moved like [move_corresponding](MoveCorresponding::move_corresponding), so a source field
+    /// of type `Option<T>` that is [None] is not written and is not in the audit trail. The values are
+    /// formatted with [Debug](std::fmt::Debug) by default, so the target fields need to implement it.
+    /// Use the `#[corresponding(audit = path::to::function)]` attribute on a target field to format its
+    /// values with a function taking a reference to the value and returning a [String].
+    ///
+    /// Implemented for the structs with the `#[corresponding(audited)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(audited)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::{FieldChange, MoveCorrespondingAudited};
+    /// use my_mod::*;
+    ///
+    /// let mut a = A { a: 1, b: 1, c: 1 };
+    ///
+    /// let changes = a.move_corresponding_audited(B { a: 2, b: None, d: 2 });
+    /// assert_eq!(
+    ///     changes,
+    ///     vec![FieldChange { field: "a", old: "1".to_string(), new: "2".to_string() }]
+    /// );
+    /// ```
+    fn move_corresponding_audited(&mut self, rhs: R) -> Vec<FieldChange>;
+}
+
+/// The old and new value of a field written by
+/// [move_corresponding_audited](MoveCorrespondingAudited::move_corresponding_audited).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// The name of the field
+    pub field: &'static str,
+    /// The formatted value of the field before it was written
+    pub old: String,
+    /// The formatted value of the field after it was written
+    pub new: String,
+}
+
+/// Trait holding the [move_corresponding_with_undo](MoveCorrespondingWithUndo::move_corresponding_with_undo) function.
+pub trait MoveCorrespondingWithUndo<R> {
+    /// The patch with the previous values of the corresponding fields
+    type Undo;
+
+    /// Move the corresponding fields from `rhs` to `self` and return a patch with the previous
+    /// values of the fields of `self` that are written.
+    ///
+    /// The patch is a struct generated in the module, named like `AUndoFromB` for target `A` and source `B`.
+    /// It has an `Option` field for every corresponding field of the target struct, with the same name and
+    /// visibility, or the path joined with `_` for the fields of a flattened field. A field that is not
+    /// written is [None]. The patch implements [Debug], [Clone], [PartialEq] and [Eq] when all fields do.
+    ///
+    /// Moving the patch back to `self` with [move_corresponding](MoveCorresponding::move_corresponding)
+    /// restores the original values, also the [None] values of `Option<T>` fields.
+    ///
+    /// Implemented for the structs with the `#[corresponding(undo)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(undo)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::{MoveCorresponding, MoveCorrespondingWithUndo};
+    /// use my_mod::*;
+    ///
+    /// let mut a = A { a: 1, b: 1, c: 1 };
+    ///
+    /// let undo = a.move_corresponding_with_undo(B { a: 2, b: Some(2), d: 2 });
+    /// println!("{a:?}");      // Output: A { a: 2, b: 2, c: 1 }
+    /// println!("{undo:?}");   // Output: AUndoFromB { a: Some(1), b: Some(1) }
+    ///
+    /// a.move_corresponding(undo);
+    /// println!("{a:?}");      // Output: A { a: 1, b: 1, c: 1 }
+    /// ```
+    fn move_corresponding_with_undo(&mut self, rhs: R) -> Self::Undo;
+}
+
+/// Trait holding the [take_corresponding](TakeCorresponding::take_corresponding) function.
+pub trait TakeCorresponding<R> {
+    /// Take the corresponding fields from `rhs` and move them to `self`.
+    ///
+    /// The corresponding fields of `rhs` are replaced by their [Default] value with [std::mem::take],
+    /// so all other fields of `rhs` can still be used afterwards. Only the types of the corresponding
+    /// fields need to implement [Default], not the struct itself. Source fields of type `Option<T>` are
+    /// left [None] when they are moved.
+    ///
+    /// Implemented for the structs with the `#[corresponding(take)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(take)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::TakeCorresponding;
+    /// use my_mod::*;
+    ///
+    /// let mut a = A { a: 1, b: 1, c: 1 };
+    /// let mut b = B { a: 2, b: Some(2), d: 2 };
+    ///
+    /// a.take_corresponding(&mut b);
+    /// println!("{a:?}");      // Output: A { a: 2, b: 2, c: 1 }
+    /// println!("{b:?}");      // Output: B { a: 0, b: None, d: 2 }
+    /// ```
+    fn take_corresponding(&mut self, rhs: &mut R);
+}
+
+/// Trait holding the [swap_corresponding](SwapCorresponding::swap_corresponding) function.
+pub trait SwapCorresponding<R> {
+    /// Swap the corresponding fields of `self` and `rhs`.
+    ///
+    /// The fields are swapped with [std::mem::swap], so nothing is cloned or allocated.
+    /// Only fields with exactly the same type are swapped. Fields of type `T` and `Option<T>`
+    /// cannot be swapped without losing a value, so these fields are skipped.
+    ///
+    /// Implemented for the structs with the `#[corresponding(swap)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(swap)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::SwapCorresponding;
+    /// use my_mod::*;
+    ///
+    /// let mut a = A { a: 1, b: 1, c: 1 };
+    /// let mut b = B { a: 2, b: Some(2), d: 2 };
+    ///
+    /// a.swap_corresponding(&mut b);
+    /// println!("{a:?}");      // Output: A { a: 2, b: 1, c: 1 }
+    /// println!("{b:?}");      // Output: B { a: 1, b: Some(2), d: 2 }
+    /// ```
+    fn swap_corresponding(&mut self, rhs: &mut R);
+}
+
+/// Trait holding the [diff_corresponding](DiffCorresponding::diff_corresponding) function.
+pub trait DiffCorresponding<R, P> {
+    /// Get the corresponding fields of `rhs` whose values differ from the fields of `self` as a patch.
+    ///
+    /// A patch `P` is another struct in the module with the `#[corresponding(patch)]` attribute, deriving
+    /// [Default] with `Option<T>` fields. For every `Option<T>` field of the patch that corresponds to a
+    /// field in both `self` and `rhs`, the value of `rhs` is set in the patch when it differs from the
+    /// value of `self`. A source field of `rhs` with
+    /// type `Option<T>` that is [None] is considered unchanged. Fields of the patch that are not an
+    /// [Option] cannot tell whether a value has changed, so they are always set with the value of `rhs`.
+    /// This is useful for keys.
+    ///
+    /// Moving the patch to `self` with [move_corresponding](MoveCorresponding::move_corresponding)
+    /// makes the corresponding fields of `self` equal to `rhs`. The compared fields need to implement
+    /// [PartialEq] and [Clone].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// #[derive_corresponding]
+    /// mod my_mod {
+    ///     #[derive(Debug, Clone)]
+    ///     pub struct User {
+    ///         pub id: u8,
+    ///         pub name: String,
+    ///         pub country: String,
+    ///     }
+    ///
+    ///     #[derive(Debug, Default)]
+    ///     #[corresponding(patch)]
+    ///     pub struct UserUpdate {
+    ///         pub id: u8,
+    ///         pub name: Option<String>,
+    ///         pub country: Option<String>,
+    ///     }
+    /// }
+    /// use corresponding::DiffCorresponding;
+    /// use my_mod::*;
+    ///
+    /// let user = User { id: 1, name: "Mark".to_string(), country: "NL".to_string() };
+    /// let mut moved = user.clone();
+    /// moved.country = "US".to_string();
+    ///
+    /// let update: UserUpdate = user.diff_corresponding(&moved);
+    /// println!("{update:?}");     // Output: UserUpdate { id: 1, name: None, country: Some("US") }
+    /// ```
+    fn diff_corresponding(&self, rhs: &R) -> P;
+}
+
+/// Trait holding the [eq_corresponding](EqCorresponding::eq_corresponding) function.
+pub trait EqCorresponding<R> {
+    /// Compare the corresponding fields of `self` and `rhs`.
+    ///
+    /// Only the fields both structs share are compared, with the same rules for `Option<T>` and `T`
+    /// as [move_corresponding](MoveCorresponding::move_corresponding). So this function returns `true`
+    /// when moving the corresponding fields of `rhs` to `self` would not change `self`. For example a source
+    /// field of type `Option<T>` that is [None] is equal to every value of the target field.
+    /// The compared fields need to implement [PartialEq].
+    ///
+    /// Implemented for the structs with the `#[corresponding(eq)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(eq)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::EqCorresponding;
+    /// use my_mod::*;
+    ///
+    /// let a = A { a: 1, b: 1, c: 1 };
+    ///
+    /// assert!(a.eq_corresponding(&B { a: 1, b: Some(1), d: 2 }));
+    /// assert!(a.eq_corresponding(&B { a: 1, b: None, d: 2 }));
+    /// assert!(!a.eq_corresponding(&B { a: 1, b: Some(2), d: 2 }));
+    /// ```
+    fn eq_corresponding(&self, rhs: &R) -> bool;
+}
+
+/// Trait holding the [move_corresponding_checked](MoveCorrespondingChecked::move_corresponding_checked) function.
+pub trait MoveCorrespondingChecked<R> {
+    /// Move the corresponding fields from `rhs` to `self`, and return a [Conflict] when a field
+    /// is written twice with different values.
+    ///
+    /// This is useful for a tuple of structs, where more structs can have the same field. Where
+    /// [move_corresponding](MoveCorresponding::move_corresponding) lets the last struct win, this
+    /// function returns an error when the values of the structs differ. Writing the same value twice
+    /// is not a conflict. On a conflict, the fields written before the conflict keep their new values.
+    /// The written fields need to implement [PartialEq].
+    ///
+    /// Implemented for the structs with the `#[corresponding(checked)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(checked)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::{Conflict, MoveCorrespondingChecked};
+    /// use my_mod::*;
+    ///
+    /// let mut a = A { a: 1, b: 1, c: 1 };
+    ///
+    /// let result = a.move_corresponding_checked((B { a: 2, b: None, d: 2 }, B { a: 2, b: Some(2), d: 2 }));
+    /// assert_eq!(result, Ok(()));
+    ///
+    /// let result = a.move_corresponding_checked((B { a: 3, b: None, d: 3 }, B { a: 4, b: None, d: 4 }));
+    /// assert_eq!(result, Err(Conflict { field: "a" }));
+    /// ```
+    fn move_corresponding_checked(&mut self, rhs: R) -> Result<(), Conflict> {
+        self.move_corresponding_checked_with(rhs, &mut Vec::new())
+    }
+
+    /// Move the corresponding fields from `rhs` to `self` like [move_corresponding_checked](MoveCorrespondingChecked::move_corresponding_checked),
+    /// also checking the fields that are already `written`. The names of the fields written from `rhs` are added to `written`.
+    fn move_corresponding_checked_with(
+        &mut self,
+        rhs: R,
+        written: &mut Vec<&'static str>,
+    ) -> Result<(), Conflict>;
+}
+
+/// A field that is written twice with different values by [MoveCorrespondingChecked]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conflict {
+    /// The name of the field
+    pub field: &'static str,
+}
+
+impl std::fmt::Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "field `{}` has conflicting values", self.field)
+    }
+}
+
+impl std::error::Error for Conflict {}
+
+/// Implement [MoveCorrespondingChecked] for a tuple of structs, by moving the corresponding fields
+/// of the structs from left to right
+macro_rules! impl_move_corresponding_checked_tuple {
+    ($($r:ident $rhs:ident),+) => {
+        impl<T, $($r),+> MoveCorrespondingChecked<($($r,)+)> for T
+        where
+            $(T: MoveCorrespondingChecked<$r>),+
+        {
+            #[inline]
+            fn move_corresponding_checked_with(
+                &mut self,
+                rhs: ($($r,)+),
+                written: &mut Vec<&'static str>,
+            ) -> Result<(), Conflict> {
+                let ($($rhs,)+) = rhs;
+                $(self.move_corresponding_checked_with($rhs, written)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_move_corresponding_checked_tuple!(R0 rhs0, R1 rhs1);
+impl_move_corresponding_checked_tuple!(R0 rhs0, R1 rhs1, R2 rhs2);
+impl_move_corresponding_checked_tuple!(R0 rhs0, R1 rhs1, R2 rhs2, R3 rhs3);
+
+/// Trait holding the [try_from_corresponding](TryFromCorresponding::try_from_corresponding) function.
+pub trait TryFromCorresponding<R>: Sized {
+    /// Construct a new struct from the corresponding fields of `rhs`, like [From], but return a [Conflict]
+    /// when a field is written twice with different values. See [MoveCorrespondingChecked].
+    ///
+    /// Implemented for the structs with the `#[corresponding(checked)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(checked)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::{Conflict, TryFromCorresponding};
+    /// use my_mod::*;
+    ///
+    /// let a = A::try_from_corresponding((B { a: 2, b: None, d: 2 }, B { a: 3, b: None, d: 3 }));
+    /// assert_eq!(a.unwrap_err(), Conflict { field: "a" });
+    /// ```
+    fn try_from_corresponding(rhs: R) -> Result<Self, Conflict>;
+}
+
+/// Trait holding the [split_corresponding](SplitCorresponding::split_corresponding) function.
+pub trait SplitCorresponding<L> {
+    /// Split `self` into a tuple of new structs, moving the corresponding fields to all parts.
+    ///
+    /// `L` is a tuple of two up to four structs of the same module, which can be constructed like with [From].
+    /// The tuple is named on the struct with `#[corresponding(split = (A, B))]`.
+    /// A field that more parts need is cloned to the earlier parts and moved to the last part,
+    /// so only these fields need to implement [Clone].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Default)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub c: Option<u8>,
+    /// #     }
+    /// #
+    /// #     #[corresponding(split = (A, B))]
+    /// #     pub struct C {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::SplitCorresponding;
+    /// use my_mod::*;
+    ///
+    /// let c = C { a: 1, b: 2, c: 3 };
+    ///
+    /// let (a, b): (A, B) = c.split_corresponding();
+    /// println!("{a:?}");      // Output: A { a: 1, b: 2 }
+    /// println!("{b:?}");      // Output: B { a: 1, c: Some(3) }
+    /// ```
+    fn split_corresponding(self) -> L;
+}
+
+/// Trait holding the [fill_corresponding](FillCorresponding::fill_corresponding) function.
+pub trait FillCorresponding<R> {
+    /// Move the corresponding fields from `rhs` to `self`, but only fill the gaps in `self`.
+    ///
+    /// A field of `self` is only written when it is [None], or equal to its [Default] value when it
+    /// is not an `Option`. So the values already in `self` are never overwritten. This is useful to merge
+    /// layers of configuration: fill from the layer with the highest priority first, and from the
+    /// defaults last. The target fields that are not an `Option` need to implement [PartialEq] and [Default].
+    ///
+    /// Implemented for the structs with the `#[corresponding(fill)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(fill)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::FillCorresponding;
+    /// use my_mod::*;
+    ///
+    /// let mut a = A { a: 1, b: 0, c: 0 };
+    ///
+    /// a.fill_corresponding(B { a: 2, b: Some(2), d: 2 });
+    /// println!("{a:?}");      // Output: A { a: 1, b: 2, c: 0 }
+    ///
+    /// a.fill_corresponding(B { a: 3, b: Some(3), d: 3 });
+    /// println!("{a:?}");      // Output: A { a: 1, b: 2, c: 0 }
+    /// ```
+    fn fill_corresponding(&mut self, rhs: R);
+}
+
+/// Trait holding the [replace_corresponding](ReplaceCorresponding::replace_corresponding) function.
+pub trait ReplaceCorresponding<R> {
+    /// Replace the fields of `self` with the corresponding fields of `rhs`, like a PUT request.
+    ///
+    /// The corresponding fields are moved like [move_corresponding](MoveCorresponding::move_corresponding),
+    /// but all other fields of `self` are reset to their [Default] value, or to the value of their
+    /// `#[corresponding(default = ...)]` option. Skipped fields are never reset. The reset fields without
+    /// a `default` option need to implement [Default].
+    ///
+    /// Implemented for the structs with the `#[corresponding(replace)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(replace)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #         pub c: u8,
+    /// #     }
+    /// #
+    /// #     #[derive(Debug, Clone)]
+    /// #     pub struct B {
+    /// #         pub a: u8,
+    /// #         pub b: Option<u8>,
+    /// #         pub d: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::ReplaceCorresponding;
+    /// use my_mod::*;
+    ///
+    /// let mut a = A { a: 1, b: 1, c: 1 };
+    ///
+    /// a.replace_corresponding(B { a: 2, b: None, d: 2 });
+    /// println!("{a:?}");      // Output: A { a: 2, b: 1, c: 0 }
+    /// ```
+    fn replace_corresponding(&mut self, rhs: R);
+}
+
+/// Trait holding the [try_move_corresponding](TryMoveCorresponding::try_move_corresponding) function.
+pub trait TryMoveCorresponding<R> {
+    /// Move the corresponding fields from `rhs` to `self`, and return a [FieldError] when a source field
+    /// of type `Result<T, E>` is an `Err`.
+    ///
+    /// Where [move_corresponding](MoveCorresponding::move_corresponding) skips a source field that is
+    /// an `Err`, this function returns its error. All source fields are checked before any field is
+    /// moved, so `self` is unchanged on an error. The error types need to convert into a boxed [Error](std::error::Error),
+    /// like the types implementing [Error](std::error::Error) and [String].
+    ///
+    /// Implemented for the structs with the `#[corresponding(try_move)]` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use corresponding::derive_corresponding;
+    /// # use std::num::ParseIntError;
+    /// # #[derive_corresponding]
+    /// # mod my_mod {
+    /// #     use std::num::ParseIntError;
+    /// #
+    /// #     #[derive(Debug, Default)]
+    /// #     #[corresponding(try_move)]
+    /// #     pub struct A {
+    /// #         pub a: u8,
+    /// #         pub b: u8,
+    /// #     }
+    /// #
+    /// #     pub struct B {
+    /// #         pub a: Result<u8, ParseIntError>,
+    /// #         pub b: u8,
+    /// #     }
+    /// # }
+    /// use corresponding::TryMoveCorresponding;
+    /// use my_mod::*;
+    ///
+    /// let mut a = A { a: 1, b: 1 };
+    ///
+    /// let result = a.try_move_corresponding(B { a: "2".parse(), b: 2 });
+    /// assert!(result.is_ok());
+    ///
+    /// let result = a.try_move_corresponding(B { a: "x".parse(), b: 3 });
+    /// assert_eq!(result.unwrap_err().to_string(), "field `a`: invalid digit found in string");
+    /// println!("{a:?}");      // Output: A { a: 2, b: 2 }
+    /// ```
+    fn try_move_corresponding(&mut self, rhs: R) -> Result<(), FieldError>;
+}
+
+/// The error of a source field of type `Result<T, E>` that is an `Err`, returned by [TryMoveCorresponding]
+#[derive(Debug)]
+pub struct FieldError {
+    /// The name of the source field
+    pub field: &'static str,
+    /// The error of the source field
+    pub error: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "field `{}`: {}", self.field, self.error)
+    }
+}
+
+impl std::error::Error for FieldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.error)
+    }
+}
+
 pub trait CloneCorresponding<R: Clone> {
     fn clone_corresponding(&mut self, rhs: &R);
 }
@@ -1 +1,7 @@
-pub use crate::{CloneCorresponding, ClonedInto, FromCloned, MoveCorresponding};
+pub use crate::{
+    CloneCorresponding, ClonedInto, CorrespondingFields, DiffCorresponding, EqCorresponding,
+    FieldNames, FillCorresponding, FromCloned, MoveCorresponding, MoveCorrespondingAudited,
+    MoveCorrespondingChanged, MoveCorrespondingChecked, MoveCorrespondingTracked,
+    MoveCorrespondingWithUndo, ReplaceCorresponding, SplitCorresponding, SwapCorresponding,
+    TakeCorresponding, TryFromCorresponding, TryMoveCorresponding,
+};
@@ -0,0 +1,46 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[corresponding(fields)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub country: String,
+        pub email: Option<String>,
+    }
+
+    #[corresponding(fields)]
+    pub struct UserDto {
+        pub id: u8,
+        pub name: String,
+        pub email: Option<String>,
+    }
+
+    #[corresponding(fields)]
+    pub struct Empty {}
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_assert_corresponds() {
+    assert_corresponds!(User => UserDto, [id, name, email], ignore [country]);
+    assert_corresponds!(UserDto => User, [email, id, name,]);
+    assert_corresponds!(User => Empty, [], ignore [id, name, country, email]);
+}
+
+#[test]
+#[should_panic(expected = "the fields moved from `UserDto` to `User` are not the given fields")]
+fn test_assert_corresponds_missing_field() {
+    assert_corresponds!(UserDto => User, [id, name, country, email]);
+}
+
+#[test]
+#[should_panic(
+    expected = "the fields [\"country\"] of `User` are not moved to `UserDto` and not ignored"
+)]
+fn test_assert_corresponds_source_field() {
+    // `country` is only a field of `User`
+    assert_corresponds!(User => UserDto, [id, name, email]);
+}
@@ -0,0 +1,81 @@
+use corresponding::*;
+
+pub fn full_name(row: &UserRow) -> String {
+    format!("{} {}", row.first, row.last)
+}
+
+pub fn form_full_name(form: &UserForm) -> String {
+    let first = form.first.as_deref().unwrap_or_default();
+    let last = form.last.as_deref().unwrap_or_default();
+    format!("{first} {last}")
+}
+
+pub fn initials(row: &UserRow) -> String {
+    format!("{}{}", &row.first[..1], &row.last[..1])
+}
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    #[corresponding(eq)]
+    pub struct User {
+        pub id: u8,
+        #[corresponding(compute(UserRow = super::full_name, UserForm = super::form_full_name))]
+        pub full_name: String,
+        #[corresponding(compute(UserRow = super::initials))]
+        pub initials: String,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserRow {
+        pub id: u8,
+        pub first: String,
+        pub last: String,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserForm {
+        pub first: Option<String>,
+        pub last: Option<String>,
+    }
+
+    // Has no function computing the full name, so it isn't moved
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserKey {
+        pub id: u8,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_compute() {
+    let user_row = UserRow {
+        id: 1,
+        first: "Mark".to_string(),
+        last: "Jansen".to_string(),
+    };
+
+    let user: User = user_row.cloned_into();
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            full_name: "Mark Jansen".to_string(),
+            initials: "MJ".to_string(),
+        }
+    );
+    assert!(user.eq_corresponding(&user_row));
+
+    let mut user = User::default();
+    user.move_corresponding(UserForm {
+        first: Some("Mark".to_string()),
+        last: None,
+    });
+    assert_eq!(user.full_name, "Mark ".to_string());
+    assert_eq!(user.initials, String::new());
+
+    user.move_corresponding(UserKey { id: 2 });
+    assert_eq!(user.id, 2);
+    assert_eq!(user.full_name, "Mark ".to_string());
+}
@@ -0,0 +1,55 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[corresponding(fields)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub country: String,
+        pub email: Option<String>,
+    }
+
+    #[corresponding(fields)]
+    pub struct UserUpdate {
+        pub id: u8,
+        pub name: Option<String>,
+        pub email: Option<String>,
+        pub password: String,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_corresponding_fields() {
+    assert_eq!(
+        <User as CorrespondingFields<UserUpdate>>::FIELDS,
+        &[
+            FieldMapping {
+                source: "id",
+                target: "id",
+                source_option: false,
+                target_option: false,
+            },
+            FieldMapping {
+                source: "name",
+                target: "name",
+                source_option: true,
+                target_option: false,
+            },
+            FieldMapping {
+                source: "email",
+                target: "email",
+                source_option: true,
+                target_option: true,
+            },
+        ]
+    );
+
+    let columns: Vec<&str> = <UserUpdate as CorrespondingFields<User>>::FIELDS
+        .iter()
+        .map(|mapping| mapping.target)
+        .collect();
+    assert_eq!(columns, vec!["id", "name", "email"]);
+}
@@ -0,0 +1,87 @@
+use corresponding::*;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Status {
+    New,
+    Active,
+}
+
+#[derive_corresponding]
+mod test_mod {
+    use super::Status;
+
+    // No Default, because Status doesn't implement it
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    #[corresponding(exhaustive)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        #[corresponding(default = Status::New)]
+        pub status: Status,
+        #[corresponding(default = "NL".to_string())]
+        pub country: String,
+    }
+
+    #[derive(Default, Debug, Clone, Eq, PartialEq)]
+    pub struct UserInsert {
+        pub id: u8,
+        pub name: String,
+        pub country: Option<String>,
+        #[corresponding(default = 3)]
+        pub retries: u8,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_default_value() {
+    let user: User = UserInsert {
+        id: 1,
+        name: "Mark".to_string(),
+        country: None,
+        retries: 0,
+    }
+    .into();
+
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "Mark".to_string(),
+            status: Status::New,
+            country: "NL".to_string(),
+        }
+    );
+
+    let user: User = UserInsert {
+        country: Some("US".to_string()),
+        ..Default::default()
+    }
+    .into();
+    assert_eq!(user.country, "US".to_string());
+}
+
+#[test]
+fn test_default_value_with_default() {
+    let user = User {
+        id: 1,
+        name: "Mark".to_string(),
+        status: Status::Active,
+        country: "NL".to_string(),
+    };
+
+    let user_insert: UserInsert = user.cloned_into();
+    assert_eq!(
+        user_insert,
+        UserInsert {
+            id: 1,
+            name: "Mark".to_string(),
+            country: Some("NL".to_string()),
+            retries: 3,
+        }
+    );
+
+    let user_insert: UserInsert = user.into();
+    assert_eq!(user_insert.retries, 3);
+}
@@ -0,0 +1,96 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Clone)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub country: String,
+        pub age: Option<u8>,
+        pub email: Option<String>,
+    }
+
+    pub struct UserForm {
+        pub id: u8,
+        pub name: Option<String>,
+        pub country: String,
+        pub age: u8,
+        pub email: Option<String>,
+    }
+
+    #[derive(Default, Debug, Eq, PartialEq)]
+    #[corresponding(patch)]
+    pub struct UserUpdate {
+        pub id: u8,
+        pub name: Option<String>,
+        pub country: Option<String>,
+        pub age: Option<u8>,
+        pub email: Option<String>,
+    }
+}
+
+pub use test_mod::*;
+
+fn user() -> User {
+    User {
+        id: 1,
+        name: "Mark".to_string(),
+        country: "NL".to_string(),
+        age: None,
+        email: Some("mark@example.com".to_string()),
+    }
+}
+
+#[test]
+fn test_diff_corresponding() {
+    let mut other = user();
+    other.country = "US".to_string();
+    other.age = Some(40);
+
+    let update: UserUpdate = user().diff_corresponding(&other);
+
+    assert_eq!(
+        update,
+        UserUpdate {
+            id: 1,
+            country: Some("US".to_string()),
+            age: Some(40),
+            ..Default::default()
+        }
+    );
+
+    let mut user = user();
+    user.move_corresponding(update);
+    let update: UserUpdate = user.diff_corresponding(&other);
+    assert_eq!(
+        update,
+        UserUpdate {
+            id: 1,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn test_diff_corresponding_option() {
+    let form = UserForm {
+        id: 1,
+        name: None,
+        country: "NL".to_string(),
+        age: 40,
+        email: Some("mark@example.org".to_string()),
+    };
+
+    let update: UserUpdate = user().diff_corresponding(&form);
+
+    assert_eq!(
+        update,
+        UserUpdate {
+            id: 1,
+            age: Some(40),
+            email: Some("mark@example.org".to_string()),
+            ..Default::default()
+        }
+    );
+}
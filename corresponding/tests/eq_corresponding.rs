@@ -0,0 +1,81 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Clone)]
+    #[corresponding(eq)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub country: Option<String>,
+        pub age: u8,
+        pub email: Option<String>,
+        #[corresponding(option = "overwrite")]
+        pub phone: Option<String>,
+    }
+
+    #[derive(Debug, Clone)]
+    #[corresponding(eq)]
+    pub struct UserDto {
+        pub id: u8,
+        pub name: String,
+        pub country: String,
+        pub age: Option<u8>,
+        pub email: Option<String>,
+        pub phone: Option<String>,
+        pub token: String,
+    }
+}
+
+pub use test_mod::*;
+
+fn user() -> User {
+    User {
+        id: 1,
+        name: "Mark".to_string(),
+        country: Some("NL".to_string()),
+        age: 40,
+        email: Some("mark@example.com".to_string()),
+        phone: None,
+    }
+}
+
+fn user_dto() -> UserDto {
+    UserDto {
+        id: 1,
+        name: "Mark".to_string(),
+        country: "NL".to_string(),
+        age: None,
+        email: None,
+        phone: None,
+        token: "secret".to_string(),
+    }
+}
+
+#[test]
+fn test_eq_corresponding() {
+    assert!(user().eq_corresponding(&user_dto()));
+    assert!(!user_dto().eq_corresponding(&user()));
+
+    let mut user_dto = user_dto();
+    user_dto.age = Some(40);
+    assert!(user().eq_corresponding(&user_dto));
+
+    user_dto.age = Some(41);
+    assert!(!user().eq_corresponding(&user_dto));
+}
+
+#[test]
+fn test_eq_corresponding_option() {
+    let mut dto = user_dto();
+    dto.phone = Some("0123456789".to_string());
+    assert!(!user().eq_corresponding(&dto));
+
+    let mut user = user();
+    user.phone = Some("0123456789".to_string());
+    assert!(!user.eq_corresponding(&user_dto()));
+    assert!(user.eq_corresponding(&dto));
+
+    user.country = None;
+    assert!(!user.eq_corresponding(&dto));
+}
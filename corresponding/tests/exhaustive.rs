@@ -0,0 +1,102 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Default, Debug, Eq, PartialEq)]
+    #[corresponding(exhaustive, fields)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub email: Option<String>,
+        #[corresponding(skip)]
+        pub version: u8,
+    }
+
+    pub struct UserRow {
+        pub id: u8,
+        pub name: Option<String>,
+        pub email: String,
+        pub version: u8,
+        pub password: String,
+    }
+}
+
+pub use test_mod::*;
+
+#[derive_corresponding]
+mod test_flatten_mod {
+    // `Address` is only a part of the other structs, so it is not checked
+    #[derive(Default, Debug, Eq, PartialEq)]
+    #[corresponding(exhaustive)]
+    pub struct Account {
+        pub id: u8,
+        #[corresponding(flatten)]
+        pub address: Address,
+    }
+
+    #[derive(Default, Debug, Clone, Eq, PartialEq)]
+    pub struct Address {
+        pub street: String,
+        pub city: String,
+    }
+
+    // Moves `address` as a whole
+    pub struct AccountRow {
+        pub id: u8,
+        pub address: Address,
+    }
+
+    // Moves `street` and `city` to the flattened field
+    pub struct AccountForm {
+        pub id: u8,
+        pub street: String,
+        pub city: String,
+    }
+}
+
+pub use test_flatten_mod::*;
+
+#[test]
+fn test_exhaustive() {
+    let user: User = UserRow {
+        id: 1,
+        name: Some("Mark".to_string()),
+        email: "mark@example.com".to_string(),
+        version: 2,
+        password: "secret".to_string(),
+    }
+    .into();
+
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "Mark".to_string(),
+            email: Some("mark@example.com".to_string()),
+            version: 0,
+        }
+    );
+    assert_corresponds!(UserRow => User, [id, name, email], ignore [version, password]);
+}
+
+#[test]
+fn test_exhaustive_flatten() {
+    let address = Address {
+        street: "Dam".to_string(),
+        city: "Amsterdam".to_string(),
+    };
+    let account: Account = AccountRow {
+        id: 1,
+        address: address.clone(),
+    }
+    .into();
+    assert_eq!(account, Account { id: 1, address });
+
+    let account: Account = AccountForm {
+        id: 2,
+        street: "Coolsingel".to_string(),
+        city: "Rotterdam".to_string(),
+    }
+    .into();
+    assert_eq!(account.address.city, "Rotterdam".to_string());
+}
@@ -0,0 +1,54 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    #[corresponding(fill)]
+    pub struct Config {
+        pub host: String,
+        pub port: u16,
+        pub user: Option<String>,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct ConfigLayer {
+        pub host: Option<String>,
+        pub port: Option<u16>,
+        pub user: Option<String>,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_fill_corresponding() {
+    let env = ConfigLayer {
+        port: Some(8080),
+        ..Default::default()
+    };
+    let file = ConfigLayer {
+        host: Some("example.com".to_string()),
+        port: Some(80),
+        user: Some("mark".to_string()),
+    };
+    let defaults = Config {
+        host: "localhost".to_string(),
+        port: 443,
+        user: None,
+    };
+
+    // Fill from the layer with the highest priority first
+    let mut config = Config::default();
+    config.fill_corresponding(env);
+    config.fill_corresponding(file);
+    config.fill_corresponding(defaults);
+
+    assert_eq!(
+        config,
+        Config {
+            host: "example.com".to_string(),
+            port: 8080,
+            user: Some("mark".to_string()),
+        }
+    );
+}
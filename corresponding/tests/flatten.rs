@@ -0,0 +1,96 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    #[corresponding(tracked)]
+    pub struct User {
+        pub id: u8,
+        #[corresponding(flatten)]
+        pub address: Address,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct Address {
+        pub street: String,
+        pub city: String,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserRow {
+        pub id: u8,
+        pub street: String,
+        pub city: Option<String>,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct Account {
+        pub id: u8,
+        #[corresponding(flatten)]
+        pub address: Address,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserDto {
+        pub id: u8,
+        pub address: Address,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_flatten() {
+    let user_row = UserRow {
+        id: 1,
+        street: "Main Street".to_string(),
+        city: Some("Amsterdam".to_string()),
+    };
+
+    let mut user: User = user_row.clone().into();
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            address: Address {
+                street: "Main Street".to_string(),
+                city: "Amsterdam".to_string(),
+            },
+        }
+    );
+    assert_eq!(UserRow::from(user.clone()), user_row);
+
+    let changes = user.move_corresponding_tracked(UserRow {
+        city: None,
+        ..user_row
+    });
+    assert_eq!(changes, vec!["id", "address.street"]);
+}
+
+#[test]
+fn test_flatten_whole_field() {
+    let user_dto = UserDto {
+        id: 2,
+        address: Address {
+            street: "Main Street".to_string(),
+            city: "Amsterdam".to_string(),
+        },
+    };
+
+    let user: User = user_dto.clone().into();
+    assert_eq!(user.address, user_dto.address);
+}
+
+#[test]
+fn test_flatten_both() {
+    let account = Account {
+        id: 3,
+        address: Address {
+            street: "Main Street".to_string(),
+            city: "Amsterdam".to_string(),
+        },
+    };
+
+    let user: User = account.clone().into();
+    assert_eq!(user.address, account.address);
+}
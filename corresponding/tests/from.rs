@@ -0,0 +1,123 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    #[corresponding(fields, eq)]
+    pub struct User {
+        pub id: u8,
+        #[corresponding(from = "address.city")]
+        pub city: String,
+        #[corresponding(from = "profile?.settings?.theme")]
+        pub theme: String,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserView {
+        #[corresponding(from = "profile?.avatar", option = "overwrite")]
+        pub avatar: Option<String>,
+        #[corresponding(from = "profile?.bio")]
+        pub bio: String,
+        #[corresponding(from = "profile?.settings?.theme")]
+        pub theme: String,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserRow {
+        pub id: u8,
+        pub address: Address,
+        pub profile: Option<Profile>,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct Address {
+        pub city: String,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct Profile {
+        pub avatar: Option<String>,
+        pub bio: String,
+        pub settings: Option<Settings>,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct Settings {
+        pub theme: String,
+    }
+}
+
+pub use test_mod::*;
+
+fn user_row(settings: Option<Settings>) -> UserRow {
+    UserRow {
+        id: 1,
+        address: Address {
+            city: "Amsterdam".to_string(),
+        },
+        profile: Some(Profile {
+            avatar: Some("avatar.png".to_string()),
+            bio: "Hello".to_string(),
+            settings,
+        }),
+    }
+}
+
+#[test]
+fn test_from() {
+    let settings = Settings {
+        theme: "dark".to_string(),
+    };
+
+    let mut user: User = user_row(Some(settings)).into();
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            city: "Amsterdam".to_string(),
+            theme: "dark".to_string(),
+        }
+    );
+
+    // The theme is only set when all `Option` fields on the path are `Some`
+    user.move_corresponding(user_row(None));
+    assert_eq!(user.theme, "dark".to_string());
+    assert!(!user.eq_corresponding(&user_row(Some(Settings::default()))));
+
+    assert_eq!(
+        <User as CorrespondingFields<UserRow>>::FIELDS
+            .iter()
+            .map(|mapping| mapping.source)
+            .collect::<Vec<_>>(),
+        vec!["address.city", "profile?.settings?.theme", "id"]
+    );
+}
+
+#[test]
+fn test_from_cloned() {
+    let mut user_row = user_row(None);
+
+    let mut user_view: UserView = user_row.cloned_into();
+    assert_eq!(user_view.avatar, Some("avatar.png".to_string()));
+
+    user_row.profile = None;
+    user_view.clone_corresponding(&user_row);
+    assert_eq!(user_view.avatar, None);
+}
+
+#[test]
+fn test_from_same_option() {
+    // All fields read through the same `Option` are moved
+    let settings = Settings {
+        theme: "dark".to_string(),
+    };
+    let user_view: UserView = user_row(Some(settings)).into();
+    assert_eq!(
+        user_view,
+        UserView {
+            avatar: Some("avatar.png".to_string()),
+            bio: "Hello".to_string(),
+            theme: "dark".to_string(),
+        }
+    );
+}
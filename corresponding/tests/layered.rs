@@ -0,0 +1,61 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    #[corresponding(tracked)]
+    pub struct Config {
+        pub host: String,
+        pub port: u16,
+        pub user: Option<String>,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct ConfigLayer {
+        pub host: Option<String>,
+        pub port: Option<u16>,
+        pub user: Option<String>,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_layered() {
+    let defaults = ConfigLayer {
+        host: Some("localhost".to_string()),
+        port: Some(80),
+        user: None,
+    };
+    let file = ConfigLayer {
+        host: Some("example.com".to_string()),
+        ..Default::default()
+    };
+    let env = ConfigLayer {
+        port: Some(8080),
+        ..Default::default()
+    };
+
+    let config = Layered::new(Config::default())
+        .with_layer("defaults", defaults)
+        .with_layer("file", file)
+        .with_layer("env", env);
+
+    assert_eq!(config.host, "example.com".to_string());
+    assert_eq!(config.port, 8080);
+    assert_eq!(
+        config.sources().collect::<Vec<_>>(),
+        vec![("host", "file"), ("port", "env")]
+    );
+    assert_eq!(config.source("user"), None);
+    assert_eq!(config.layers(), ["defaults", "file", "env"]);
+
+    assert_eq!(
+        config.into_inner(),
+        Config {
+            host: "example.com".to_string(),
+            port: 8080,
+            user: None,
+        }
+    );
+}
@@ -0,0 +1,116 @@
+use corresponding::*;
+use std::collections::BTreeSet;
+
+pub fn join(notes: &mut String, note: String) {
+    if !notes.is_empty() {
+        notes.push_str(", ");
+    }
+    notes.push_str(&note);
+}
+
+#[derive_corresponding]
+mod test_mod {
+    use std::collections::BTreeSet;
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    #[corresponding(tracked, changed, undo, checked)]
+    pub struct Stats {
+        #[corresponding(merge = "append")]
+        pub names: Vec<String>,
+        #[corresponding(merge = "extend")]
+        pub tags: BTreeSet<String>,
+        #[corresponding(merge = "sum")]
+        pub count: u32,
+        #[corresponding(merge = "sum", option = "default")]
+        pub total: u32,
+        #[corresponding(merge = "max")]
+        pub max: u8,
+        #[corresponding(merge = super::join)]
+        pub notes: String,
+        pub last: u8,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct StatsPart {
+        pub names: Vec<String>,
+        pub tags: BTreeSet<String>,
+        pub count: Option<u32>,
+        pub total: Option<u32>,
+        pub max: u8,
+        pub notes: String,
+        pub last: u8,
+    }
+}
+
+pub use test_mod::*;
+
+fn part(name: &str, count: Option<u32>, max: u8) -> StatsPart {
+    StatsPart {
+        names: vec![name.to_string()],
+        tags: BTreeSet::from(["a".to_string(), name.to_string()]),
+        count,
+        total: count,
+        max,
+        notes: name.to_string(),
+        last: max,
+    }
+}
+
+#[test]
+fn test_merge() {
+    let mut stats = Stats::default();
+    stats.move_corresponding(part("x", Some(2), 5));
+    stats.move_corresponding(part("y", None, 3));
+    stats.move_corresponding(part("z", Some(4), 4));
+
+    assert_eq!(
+        stats,
+        Stats {
+            names: vec!["x".to_string(), "y".to_string(), "z".to_string()],
+            tags: BTreeSet::from(["a", "x", "y", "z"].map(String::from)),
+            count: 6,
+            total: 6,
+            max: 5,
+            notes: "x, y, z".to_string(),
+            last: 4,
+        }
+    );
+}
+
+#[test]
+fn test_merge_tracked() {
+    let mut stats = Stats::from(part("x", Some(2), 5));
+    let changes = stats.move_corresponding_tracked(part("y", None, 3));
+
+    assert_eq!(
+        changes,
+        vec!["names", "tags", "total", "max", "notes", "last"]
+    );
+    assert_eq!(stats.names, vec!["x".to_string(), "y".to_string()]);
+    assert_eq!(stats.count, 2);
+    assert_eq!(stats.total, 2);
+    assert_eq!(stats.max, 5);
+}
+
+#[test]
+fn test_merge_checked_changed_and_undo() {
+    // All writes merge the values like `move_corresponding`
+    let parts = (part("x", Some(2), 5), part("y", Some(3), 5));
+    let stats = Stats::try_from_corresponding(parts.clone()).unwrap();
+    assert_eq!(stats, Stats::from(parts));
+
+    let mut stats = Stats::from(part("x", Some(2), 5));
+    let changes = stats.move_corresponding_changed(part("x", None, 3));
+    assert_eq!(changes, vec!["names", "notes", "last"]);
+    assert_eq!(stats.names, vec!["x".to_string(), "x".to_string()]);
+    assert_eq!(stats.max, 5);
+
+    let original = stats.clone();
+    let undo = stats.move_corresponding_with_undo(part("z", Some(4), 7));
+    let mut moved = original.clone();
+    moved.move_corresponding(part("z", Some(4), 7));
+    assert_eq!(stats, moved);
+
+    stats.move_corresponding(undo);
+    assert_eq!(stats, original);
+}
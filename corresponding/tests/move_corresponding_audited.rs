@@ -0,0 +1,74 @@
+use corresponding::*;
+
+fn mask(value: &str) -> String {
+    "*".repeat(value.len())
+}
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Eq, PartialEq)]
+    #[corresponding(audited)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub email: Option<String>,
+        #[corresponding(audit = super::mask)]
+        pub password: String,
+    }
+
+    pub struct UserUpdate {
+        pub id: u8,
+        pub name: Option<String>,
+        pub email: String,
+        pub password: Option<String>,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_move_corresponding_audited() {
+    let mut user = User {
+        id: 1,
+        name: "Mark".to_string(),
+        email: None,
+        password: "secret".to_string(),
+    };
+
+    let changes = user.move_corresponding_audited(UserUpdate {
+        id: 1,
+        name: None,
+        email: "mark@example.com".to_string(),
+        password: Some("password".to_string()),
+    });
+
+    assert_eq!(
+        changes,
+        vec![
+            FieldChange {
+                field: "id",
+                old: "1".to_string(),
+                new: "1".to_string(),
+            },
+            FieldChange {
+                field: "email",
+                old: "None".to_string(),
+                new: "Some(\"mark@example.com\")".to_string(),
+            },
+            FieldChange {
+                field: "password",
+                old: "******".to_string(),
+                new: "********".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "Mark".to_string(),
+            email: Some("mark@example.com".to_string()),
+            password: "password".to_string(),
+        }
+    );
+}
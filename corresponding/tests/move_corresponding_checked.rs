@@ -0,0 +1,88 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    #[corresponding(checked)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub country: String,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserKey {
+        pub id: u8,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserUpdate {
+        pub id: u8,
+        pub name: Option<String>,
+        pub country: Option<String>,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_try_from_corresponding() {
+    let user = User::try_from_corresponding((
+        UserKey { id: 1 },
+        UserUpdate {
+            id: 1,
+            name: Some("Mark".to_string()),
+            country: None,
+        },
+    ));
+    assert_eq!(
+        user,
+        Ok(User {
+            id: 1,
+            name: "Mark".to_string(),
+            country: String::new(),
+        })
+    );
+
+    let user = User::try_from_corresponding((UserKey { id: 1 }, UserUpdate::default()));
+    assert_eq!(user, Err(Conflict { field: "id" }));
+}
+
+#[test]
+fn test_move_corresponding_checked() {
+    let mut user = User::default();
+
+    // A `None` doesn't write the field, so it cannot conflict
+    let result = user.move_corresponding_checked((
+        UserUpdate {
+            id: 2,
+            name: Some("Mark".to_string()),
+            country: None,
+        },
+        UserUpdate {
+            id: 2,
+            name: None,
+            country: Some("NL".to_string()),
+        },
+        UserKey { id: 2 },
+    ));
+    assert_eq!(result, Ok(()));
+    assert_eq!(user.country, "NL".to_string());
+
+    let result = user.move_corresponding_checked((
+        UserUpdate {
+            id: 2,
+            name: Some("Mark".to_string()),
+            country: None,
+        },
+        UserUpdate {
+            id: 2,
+            name: Some("Jansen".to_string()),
+            country: None,
+        },
+    ));
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "field `name` has conflicting values"
+    );
+}
@@ -0,0 +1,76 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Default, Debug, Clone, Eq, PartialEq)]
+    #[corresponding(tracked, changed)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub country: String,
+        pub email: Option<String>,
+        pub phone: Option<String>,
+    }
+
+    #[derive(Default, Clone)]
+    pub struct UserUpdate {
+        pub id: u8,
+        pub name: Option<String>,
+        pub country: Option<String>,
+        pub email: Option<String>,
+        pub phone: String,
+    }
+}
+
+pub use test_mod::*;
+
+fn user() -> User {
+    User {
+        id: 1,
+        name: "Mark".to_string(),
+        country: "NL".to_string(),
+        email: None,
+        phone: None,
+    }
+}
+
+fn user_update() -> UserUpdate {
+    UserUpdate {
+        id: 1,
+        name: Some("Mark".to_string()),
+        country: Some("US".to_string()),
+        email: None,
+        phone: "0123456789".to_string(),
+    }
+}
+
+fn expected() -> User {
+    User {
+        id: 1,
+        name: "Mark".to_string(),
+        country: "US".to_string(),
+        email: None,
+        phone: Some("0123456789".to_string()),
+    }
+}
+
+#[test]
+fn test_move_corresponding_tracked() {
+    let mut user = user();
+    let changes = user.move_corresponding_tracked(user_update());
+
+    assert_eq!(changes, vec!["id", "name", "country", "phone"]);
+    assert_eq!(user, expected());
+}
+
+#[test]
+fn test_move_corresponding_changed() {
+    let mut user = user();
+    let changes = user.move_corresponding_changed(user_update());
+
+    assert_eq!(changes, vec!["country", "phone"]);
+    assert_eq!(user, expected());
+
+    let changes = user.move_corresponding_changed(user_update());
+    assert!(changes.is_empty());
+}
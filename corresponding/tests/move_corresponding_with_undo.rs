@@ -0,0 +1,78 @@
+use corresponding::*;
+use std::rc::Rc;
+
+#[derive_corresponding]
+mod test_mod {
+    use std::rc::Rc;
+
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    #[corresponding(undo)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub country: String,
+        pub email: Option<String>,
+        pub version: Rc<u8>,
+    }
+
+    #[derive(Default)]
+    pub struct UserUpdate {
+        pub id: u8,
+        pub name: Option<String>,
+        pub country: Option<String>,
+        pub email: String,
+        pub version: Rc<u8>,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_move_corresponding_with_undo() {
+    let original = User {
+        id: 1,
+        name: "Mark".to_string(),
+        country: "NL".to_string(),
+        email: None,
+        version: Rc::new(1),
+    };
+
+    let mut user = original.clone();
+    let undo = user.move_corresponding_with_undo(UserUpdate {
+        id: 1,
+        country: Some("US".to_string()),
+        email: "mark@example.com".to_string(),
+        version: Rc::new(2),
+        ..Default::default()
+    });
+
+    // The name is not written, because it is `None`
+    assert_eq!(
+        undo,
+        UserUndoFromUserUpdate {
+            id: Some(1),
+            name: None,
+            country: Some("NL".to_string()),
+            email: Some(None),
+            version: Some(Rc::new(1)),
+        }
+    );
+    assert_eq!(undo.country, Some("NL".to_string()));
+    assert_eq!(
+        format!("{undo:?}"),
+        r#"UserUndoFromUserUpdate { id: Some(1), name: None, country: Some("NL"), email: Some(None), version: Some(1) }"#
+    );
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "Mark".to_string(),
+            country: "US".to_string(),
+            email: Some("mark@example.com".to_string()),
+            version: Rc::new(2),
+        }
+    );
+
+    user.move_corresponding(undo);
+    assert_eq!(user, original);
+}
@@ -0,0 +1,108 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Default, Debug, Clone, Eq, PartialEq)]
+    pub struct A {
+        // Only set when Some
+        pub a: u8,
+        pub b: Option<u8>,
+
+        // Set to None
+        #[corresponding(option = "overwrite")]
+        pub c: Option<u8>,
+
+        // Set to the default
+        #[corresponding(option = "default")]
+        pub d: u8,
+        #[corresponding(option = "default")]
+        pub e: Option<u8>,
+
+        // Policy of the source field
+        pub f: String,
+    }
+
+    #[derive(Clone)]
+    pub struct B {
+        pub a: Option<u8>,
+        pub b: Option<u8>,
+        pub c: Option<u8>,
+        pub d: Option<u8>,
+        pub e: Option<u8>,
+        #[corresponding(option = "default")]
+        pub f: Option<String>,
+    }
+}
+
+pub use test_mod::*;
+
+fn a() -> A {
+    A {
+        a: 1,
+        b: Some(1),
+        c: Some(1),
+        d: 1,
+        e: Some(1),
+        f: "1".to_string(),
+    }
+}
+
+fn b() -> B {
+    B {
+        a: None,
+        b: None,
+        c: None,
+        d: None,
+        e: None,
+        f: None,
+    }
+}
+
+fn expected() -> A {
+    A {
+        a: 1,
+        b: Some(1),
+        c: None,
+        d: 0,
+        e: None,
+        f: String::new(),
+    }
+}
+
+#[test]
+fn test_move_corresponding() {
+    let mut a = a();
+    a.move_corresponding(b());
+    assert_eq!(a, expected());
+}
+
+#[test]
+fn test_clone_corresponding() {
+    let mut a = a();
+    a.clone_corresponding(&b());
+    assert_eq!(a, expected());
+}
+
+#[test]
+fn test_move_corresponding_some() {
+    let mut a = a();
+    a.move_corresponding(B {
+        a: Some(2),
+        b: Some(2),
+        c: Some(2),
+        d: Some(2),
+        e: Some(2),
+        f: Some("2".to_string()),
+    });
+    assert_eq!(
+        a,
+        A {
+            a: 2,
+            b: Some(2),
+            c: Some(2),
+            d: 2,
+            e: Some(2),
+            f: "2".to_string(),
+        }
+    );
+}
@@ -0,0 +1,65 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    #[corresponding(replace)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        #[corresponding(default = "NL".to_string())]
+        pub country: String,
+        #[corresponding(skip)]
+        pub version: u8,
+        #[corresponding(flatten)]
+        pub address: Address,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct Address {
+        pub street: String,
+        pub city: String,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserPut {
+        pub id: u8,
+        pub name: Option<String>,
+        pub city: String,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_replace_corresponding() {
+    let mut user = User {
+        id: 1,
+        name: "Mark".to_string(),
+        country: "US".to_string(),
+        version: 3,
+        address: Address {
+            street: "Main Street".to_string(),
+            city: "New York".to_string(),
+        },
+    };
+
+    user.replace_corresponding(UserPut {
+        id: 1,
+        name: None,
+        city: "Amsterdam".to_string(),
+    });
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "Mark".to_string(),
+            country: "NL".to_string(),
+            version: 3,
+            address: Address {
+                street: String::new(),
+                city: "Amsterdam".to_string(),
+            },
+        }
+    );
+}
@@ -0,0 +1,88 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    use std::num::ParseIntError;
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    #[corresponding(eq, try_move)]
+    pub struct User {
+        pub id: u8,
+        pub age: u8,
+        pub email: Option<String>,
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    pub struct UserInput {
+        pub id: u8,
+        pub age: Result<u8, ParseIntError>,
+        pub email: Result<String, String>,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_result() {
+    let mut user = User {
+        id: 1,
+        age: 30,
+        email: Some("mark@example.com".to_string()),
+    };
+
+    // An `Err` doesn't set a field of type `T`, and sets a field of type `Option<T>` to `None`
+    user.move_corresponding(UserInput {
+        id: 1,
+        age: "x".parse(),
+        email: Err("invalid email".to_string()),
+    });
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            age: 30,
+            email: None,
+        }
+    );
+
+    let input = UserInput {
+        id: 2,
+        age: "31".parse(),
+        email: Ok("jansen@example.com".to_string()),
+    };
+    assert!(!user.eq_corresponding(&input));
+    user.clone_corresponding(&input);
+    assert!(user.eq_corresponding(&input));
+    assert_eq!(user, User::from(input));
+}
+
+#[test]
+fn test_try_move_corresponding() {
+    let mut user = User::default();
+
+    let result = user.try_move_corresponding(UserInput {
+        id: 1,
+        age: "31".parse(),
+        email: Err("invalid email".to_string()),
+    });
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "field `email`: invalid email"
+    );
+    assert_eq!(user, User::default());
+
+    let result = user.try_move_corresponding(UserInput {
+        id: 1,
+        age: "31".parse(),
+        email: Ok("mark@example.com".to_string()),
+    });
+    assert!(result.is_ok());
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            age: 31,
+            email: Some("mark@example.com".to_string()),
+        }
+    );
+}
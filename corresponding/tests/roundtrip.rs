@@ -0,0 +1,36 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Default, Debug, Clone, Eq, PartialEq)]
+    #[corresponding(roundtrip = UserRow)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub email: Option<String>,
+    }
+
+    #[derive(Default, Debug, Eq, PartialEq)]
+    pub struct UserRow {
+        pub id: u8,
+        pub name: String,
+        pub email: Option<String>,
+        pub version: u8,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_roundtrip() {
+    let user = User {
+        id: 1,
+        name: "Mark".to_string(),
+        email: None,
+    };
+
+    let user_row: UserRow = user.clone().into();
+    let roundtrip: User = user_row.into();
+
+    assert_eq!(roundtrip, user);
+}
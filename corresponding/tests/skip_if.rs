@@ -0,0 +1,129 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    #[corresponding(tracked, eq)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        #[corresponding(skip_if = String::is_empty)]
+        pub email: String,
+        pub nickname: Option<String>,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserForm {
+        pub id: u8,
+        #[corresponding(skip_if = str::is_empty)]
+        pub name: String,
+        pub email: String,
+        #[corresponding(skip_if = String::is_empty)]
+        pub nickname: Option<String>,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    #[corresponding(patch)]
+    pub struct UserPatch {
+        pub name: Option<String>,
+        pub email: Option<String>,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_skip_if() {
+    let mut user = User {
+        id: 1,
+        name: "Mark".to_string(),
+        email: "mark@example.com".to_string(),
+        nickname: Some("mark".to_string()),
+    };
+
+    let form = UserForm {
+        id: 2,
+        name: String::new(),
+        email: String::new(),
+        nickname: Some(String::new()),
+    };
+    user.move_corresponding(form);
+    assert_eq!(
+        user,
+        User {
+            id: 2,
+            name: "Mark".to_string(),
+            email: "mark@example.com".to_string(),
+            nickname: Some("mark".to_string()),
+        }
+    );
+
+    let form = UserForm {
+        id: 2,
+        name: "Jansen".to_string(),
+        email: "jansen@example.com".to_string(),
+        nickname: None,
+    };
+    assert_eq!(
+        user.move_corresponding_tracked(form),
+        vec!["id", "name", "email"]
+    );
+    assert_eq!(user.name, "Jansen".to_string());
+    assert_eq!(user.email, "jansen@example.com".to_string());
+}
+
+#[test]
+fn test_skip_if_reverse() {
+    // The predicate on the source field also applies when it is the target field
+    let mut form = UserForm::default();
+    form.clone_corresponding(&User {
+        id: 1,
+        name: String::new(),
+        email: "mark@example.com".to_string(),
+        nickname: Some(String::new()),
+    });
+    assert_eq!(
+        form,
+        UserForm {
+            id: 1,
+            name: String::new(),
+            email: "mark@example.com".to_string(),
+            nickname: None,
+        }
+    );
+}
+
+#[test]
+fn test_skip_if_eq_and_diff() {
+    // A value that moving would skip is neither a difference nor a change
+    let user = User {
+        id: 1,
+        name: "Mark".to_string(),
+        email: "mark@example.com".to_string(),
+        nickname: Some("mark".to_string()),
+    };
+    let form = UserForm {
+        id: 1,
+        name: String::new(),
+        email: String::new(),
+        nickname: Some(String::new()),
+    };
+    assert!(user.eq_corresponding(&form));
+    assert_eq!(
+        DiffCorresponding::<UserForm, UserPatch>::diff_corresponding(&user, &form),
+        UserPatch::default()
+    );
+
+    let form = UserForm {
+        email: "jansen@example.com".to_string(),
+        ..form
+    };
+    assert!(!user.eq_corresponding(&form));
+    assert_eq!(
+        DiffCorresponding::<UserForm, UserPatch>::diff_corresponding(&user, &form),
+        UserPatch {
+            name: None,
+            email: Some("jansen@example.com".to_string()),
+        }
+    );
+}
@@ -0,0 +1,86 @@
+use corresponding::*;
+
+// Doesn't implement Clone, so it can only be moved
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Token(String);
+
+#[derive_corresponding]
+mod test_mod {
+    use super::Token;
+
+    #[derive(Debug, Default, Eq, PartialEq)]
+    #[corresponding(split = (UserKey, UserInsert))]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub country: Option<String>,
+        pub token: Token,
+    }
+
+    #[derive(Debug, Default, Eq, PartialEq)]
+    pub struct UserKey {
+        pub id: u8,
+        pub token: Token,
+    }
+
+    #[derive(Debug, Default, Eq, PartialEq)]
+    #[corresponding(split = (UserKey, User), split = (UserKey, UserName, User))]
+    pub struct UserInsert {
+        pub id: u8,
+        pub name: String,
+        pub country: String,
+    }
+
+    #[derive(Debug, Default, Eq, PartialEq)]
+    pub struct UserName {
+        pub name: String,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_split_corresponding() {
+    let user = User {
+        id: 1,
+        name: "Mark".to_string(),
+        country: Some("NL".to_string()),
+        token: Token("secret".to_string()),
+    };
+
+    let (user_key, user_insert): (UserKey, UserInsert) = user.split_corresponding();
+    assert_eq!(
+        user_key,
+        UserKey {
+            id: 1,
+            token: Token("secret".to_string()),
+        }
+    );
+    assert_eq!(
+        user_insert,
+        UserInsert {
+            id: 1,
+            name: "Mark".to_string(),
+            country: "NL".to_string(),
+        }
+    );
+
+    let (user_key, user): (UserKey, User) = user_insert.split_corresponding();
+    assert_eq!(user_key.id, 1);
+    assert_eq!(user.id, 1);
+    assert_eq!(user.country, Some("NL".to_string()));
+}
+
+#[test]
+fn test_split_corresponding_three() {
+    let user_insert = UserInsert {
+        id: 1,
+        name: "Mark".to_string(),
+        country: "NL".to_string(),
+    };
+
+    let (user_key, user_name, user): (UserKey, UserName, User) = user_insert.split_corresponding();
+    assert_eq!(user_key.id, 1);
+    assert_eq!(user_name.name, "Mark".to_string());
+    assert_eq!(user.name, "Mark".to_string());
+}
@@ -0,0 +1,61 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Eq, PartialEq)]
+    #[corresponding(swap)]
+    pub struct A {
+        pub a: String,
+        pub b: Option<u8>,
+        pub c: u8,
+        pub d: u8,
+    }
+
+    #[derive(Debug, Eq, PartialEq)]
+    pub struct B {
+        pub a: String,
+        pub b: Option<u8>,
+        pub c: Option<u8>,
+        pub e: u8,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_swap_corresponding() {
+    let mut a = A {
+        a: "1".to_string(),
+        b: None,
+        c: 1,
+        d: 1,
+    };
+
+    let mut b = B {
+        a: "2".to_string(),
+        b: Some(2),
+        c: Some(2),
+        e: 2,
+    };
+
+    a.swap_corresponding(&mut b);
+
+    assert_eq!(
+        a,
+        A {
+            a: "2".to_string(),
+            b: Some(2),
+            c: 1,
+            d: 1,
+        }
+    );
+    assert_eq!(
+        b,
+        B {
+            a: "1".to_string(),
+            b: None,
+            c: Some(2),
+            e: 2,
+        }
+    );
+}
@@ -0,0 +1,72 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Eq, PartialEq)]
+    pub struct NoDefault {
+        pub a: u8,
+    }
+
+    #[derive(Default, Debug, Eq, PartialEq)]
+    #[corresponding(take)]
+    pub struct A {
+        pub a: String,
+        pub b: u8,
+        pub c: Option<u8>,
+        pub d: Option<u8>,
+        pub e: Vec<u8>,
+    }
+
+    #[derive(Debug, Eq, PartialEq)]
+    pub struct B {
+        pub a: String,
+        pub b: Option<u8>,
+        pub c: u8,
+        pub d: Option<u8>,
+        pub f: NoDefault,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_take_corresponding() {
+    let mut a = A {
+        a: "1".to_string(),
+        b: 1,
+        c: None,
+        d: Some(1),
+        e: vec![1],
+    };
+
+    let mut b = B {
+        a: "2".to_string(),
+        b: Some(2),
+        c: 2,
+        d: Some(2),
+        f: NoDefault { a: 2 },
+    };
+
+    a.take_corresponding(&mut b);
+
+    assert_eq!(
+        a,
+        A {
+            a: "2".to_string(),
+            b: 2,
+            c: Some(2),
+            d: Some(2),
+            e: vec![1],
+        }
+    );
+    assert_eq!(
+        b,
+        B {
+            a: String::new(),
+            b: None,
+            c: 0,
+            d: None,
+            f: NoDefault { a: 2 },
+        }
+    );
+}
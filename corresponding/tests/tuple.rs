@@ -0,0 +1,69 @@
+use corresponding::*;
+
+#[derive_corresponding]
+mod test_mod {
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct User {
+        pub id: u8,
+        pub name: String,
+        pub email: String,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserKey {
+        pub id: u8,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserInsert {
+        pub name: String,
+        pub email: Option<String>,
+    }
+
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub struct UserRename {
+        pub name: Option<String>,
+    }
+}
+
+pub use test_mod::*;
+
+#[test]
+fn test_from_tuple() {
+    let user_key = UserKey { id: 1 };
+    let user_insert = UserInsert {
+        name: "Mark".to_string(),
+        email: Some("mark@example.com".to_string()),
+    };
+
+    let user = User::from((user_key, user_insert));
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "Mark".to_string(),
+            email: "mark@example.com".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_move_corresponding_tuple_last_wins() {
+    let mut user = User::default();
+    user.move_corresponding((
+        UserKey { id: 2 },
+        UserInsert {
+            name: "Mark".to_string(),
+            email: None,
+        },
+        UserRename {
+            name: Some("Jansen".to_string()),
+        },
+    ));
+    assert_eq!(user.id, 2);
+    assert_eq!(user.name, "Jansen".to_string());
+
+    // A `None` doesn't override the value of an earlier struct
+    let user: User = (UserInsert::default(), UserRename { name: None }).into();
+    assert_eq!(user, User::default());
+}